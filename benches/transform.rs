@@ -0,0 +1,44 @@
+//! Transform throughput for C-order (row-major) and Fortran-order inputs.
+//!
+//! Run with `cargo bench --bench transform`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ndarray::{Array2, ShapeBuilder};
+use robust_scaler::RobustScaler;
+
+const ROWS: usize = 200_000;
+const COLS: usize = 32;
+const ITERATIONS: u32 = 20;
+
+/// Returns the mean time of `f` over `ITERATIONS` runs, after one warm-up run.
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let c_order = Array2::from_shape_fn((ROWS, COLS), |(i, j)| ((i * 31 + j * 7) % 1000) as f64);
+    let mut f_order = Array2::zeros((ROWS, COLS).f());
+    f_order.assign(&c_order);
+
+    let mut scaler = RobustScaler::new();
+    scaler.fit(&c_order);
+
+    for (layout, data) in [("C-order", &c_order), ("F-order", &f_order)] {
+        let transform = time(|| {
+            black_box(scaler.transform(black_box(data)));
+        });
+        let mut buffer = data.clone();
+        let inplace = time(|| scaler.transform_inplace(black_box(&mut buffer)));
+        println!(
+            "{} {}x{}: transform {:?}, transform_inplace {:?}",
+            layout, ROWS, COLS, transform, inplace
+        );
+    }
+}
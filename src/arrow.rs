@@ -0,0 +1,235 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{ArrayRef, Float64Array, RecordBatch};
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_ipc::writer::{FileWriter, StreamWriter};
+use arrow_schema::{DataType, Schema};
+use ndarray::Array2;
+
+use crate::{RobustScaler, ScalerError};
+
+impl RobustScaler {
+    /// Scales the feature columns of an Arrow `RecordBatch` (enabled by the
+    /// `arrow` feature).
+    ///
+    /// With feature names, the columns of those names are scaled; without,
+    /// every `Float64` column is a feature, in order. Other columns are passed
+    /// through unchanged and the schema is kept. Nulls are missing values
+    /// (see [`RobustScaler::with_missing_policy`]) and stay null unless the
+    /// policy replaces them.
+    ///
+    /// # Returns
+    /// The scaled batch, `ScalerError::MissingFeature` or
+    /// `ScalerError::UnsupportedColumnType` if a feature column is absent or
+    /// not `Float64`, or an error of [`RobustScaler::try_transform`].
+    pub fn transform_record_batch(&self, batch: &RecordBatch) -> Result<RecordBatch, ScalerError> {
+        let indices = self.feature_columns(&batch.schema())?;
+        let mut data = Array2::zeros((batch.num_rows(), indices.len()));
+        for (j, &index) in indices.iter().enumerate() {
+            let column = batch.column(index).as_primitive::<Float64Type>();
+            for (x, value) in data.column_mut(j).iter_mut().zip(column) {
+                *x = value.unwrap_or(f64::NAN);
+            }
+        }
+        let scaled = self.try_transform(&data)?;
+
+        let mut columns = batch.columns().to_vec();
+        for (j, &index) in indices.iter().enumerate() {
+            let original = batch.column(index).as_primitive::<Float64Type>();
+            let values = scaled.column(j);
+            let column: Float64Array = original
+                .iter()
+                .zip(values)
+                .map(|(value, &x)| (value.is_some() || !x.is_nan()).then_some(x))
+                .collect();
+            columns[index] = Arc::new(column) as ArrayRef;
+        }
+        Ok(RecordBatch::try_new(batch.schema(), columns).expect("The columns match the schema"))
+    }
+
+    /// Scales an Arrow IPC stream batch by batch, writing a stream with the same schema.
+    ///
+    /// Only one batch is held in memory at a time, so inputs of any size can
+    /// be rescaled. Columns are selected as in [`RobustScaler::transform_record_batch`].
+    ///
+    /// # Arguments
+    /// * `input` - An Arrow IPC stream, e.g. `std::io::stdin()`.
+    /// * `output` - Where the scaled stream is written.
+    ///
+    /// # Returns
+    /// `Ok(n_rows)` with the number of rows scaled, `Err` if the stream is
+    /// invalid or a batch cannot be scaled.
+    pub fn transform_ipc_stream<R: Read, W: Write>(
+        &self,
+        input: R,
+        output: W,
+    ) -> io::Result<usize> {
+        let reader = StreamReader::try_new(input, None).map_err(io::Error::other)?;
+        let mut writer =
+            StreamWriter::try_new(output, &reader.schema()).map_err(io::Error::other)?;
+        let mut n_rows = 0;
+        for batch in reader {
+            let batch = self.transform_record_batch(&batch.map_err(io::Error::other)?)?;
+            writer.write(&batch).map_err(io::Error::other)?;
+            n_rows += batch.num_rows();
+        }
+        writer.finish().map_err(io::Error::other)?;
+        Ok(n_rows)
+    }
+
+    /// Scales an Arrow IPC (Feather version 2) file batch by batch into a new file.
+    ///
+    /// See [`RobustScaler::transform_ipc_stream`].
+    ///
+    /// # Returns
+    /// `Ok(n_rows)` with the number of rows scaled, `Err` otherwise.
+    pub fn transform_ipc_file<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        input: P,
+        output: Q,
+    ) -> io::Result<usize> {
+        let input = BufReader::new(File::open(input.as_ref())?);
+        let mut output = BufWriter::new(File::create(output.as_ref())?);
+        let n_rows = self.transform_ipc_file_io(input, &mut output)?;
+        output.flush()?;
+        Ok(n_rows)
+    }
+
+    fn transform_ipc_file_io<R: Read + Seek, W: Write>(
+        &self,
+        input: R,
+        output: W,
+    ) -> io::Result<usize> {
+        let reader = FileReader::try_new(input, None).map_err(io::Error::other)?;
+        let mut writer = FileWriter::try_new(output, &reader.schema()).map_err(io::Error::other)?;
+        let mut n_rows = 0;
+        for batch in reader {
+            let batch = self.transform_record_batch(&batch.map_err(io::Error::other)?)?;
+            writer.write(&batch).map_err(io::Error::other)?;
+            n_rows += batch.num_rows();
+        }
+        writer.finish().map_err(io::Error::other)?;
+        Ok(n_rows)
+    }
+
+    /// Finds the index of the column of each feature.
+    fn feature_columns(&self, schema: &Schema) -> Result<Vec<usize>, ScalerError> {
+        let indices: Vec<usize> = match &self.feature_names_ {
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    schema
+                        .index_of(name)
+                        .map_err(|_| ScalerError::MissingFeature { name: name.clone() })
+                })
+                .collect::<Result<_, _>>()?,
+            None => (0..schema.fields().len())
+                .filter(|&i| schema.field(i).data_type() == &DataType::Float64)
+                .collect(),
+        };
+        if indices.len() != self.n_features() {
+            return Err(ScalerError::FeatureCountMismatch {
+                expected: self.n_features(),
+                found: indices.len(),
+            });
+        }
+        for &index in &indices {
+            let field = schema.field(index);
+            if field.data_type() != &DataType::Float64 {
+                return Err(ScalerError::UnsupportedColumnType {
+                    name: field.name().clone(),
+                    data_type: field.data_type().to_string(),
+                });
+            }
+        }
+        Ok(indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::StringArray;
+    use arrow_schema::Field;
+    use std::io::Cursor;
+
+    fn batch(a: Vec<Option<f64>>, ids: Vec<&str>) -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("a", DataType::Float64, true),
+        ]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(ids)),
+            Arc::new(Float64Array::from(a)),
+        ];
+        RecordBatch::try_new(Arc::new(schema), columns).unwrap()
+    }
+
+    #[test]
+    fn test_transform_ipc() {
+        let scaler = RobustScaler::from_params(vec![1.0], vec![2.0])
+            .unwrap()
+            .with_feature_names(vec!["a"]);
+        let batches = [
+            batch(vec![Some(1.0), None], vec!["x", "y"]),
+            batch(vec![Some(5.0)], vec!["z"]),
+        ];
+
+        let mut input = Vec::new();
+        let mut writer = StreamWriter::try_new(&mut input, &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut output = Vec::new();
+        let n_rows = scaler
+            .transform_ipc_stream(input.as_slice(), &mut output)
+            .unwrap();
+        assert_eq!(n_rows, 3);
+
+        let scaled: Vec<RecordBatch> = StreamReader::try_new(output.as_slice(), None)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(scaled[0].schema(), batches[0].schema());
+        assert_eq!(scaled[0].column(0), batches[0].column(0));
+        let a = scaled[0].column(1).as_primitive::<Float64Type>();
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![Some(0.0), None]);
+        assert_eq!(
+            scaled[1].column(1).as_primitive::<Float64Type>().value(0),
+            2.0
+        );
+
+        let mut file = Cursor::new(Vec::new());
+        let mut writer = FileWriter::try_new(&mut file, &batches[0].schema()).unwrap();
+        writer.write(&batches[1]).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+        file.set_position(0);
+        let mut output = Vec::new();
+        assert_eq!(scaler.transform_ipc_file_io(file, &mut output).unwrap(), 1);
+
+        let unnamed = RobustScaler::from_params(vec![0.0, 0.0], vec![1.0, 1.0]).unwrap();
+        assert_eq!(
+            unnamed.transform_record_batch(&batches[0]).err(),
+            Some(ScalerError::FeatureCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        let wrong_type = RobustScaler::from_params(vec![0.0], vec![1.0])
+            .unwrap()
+            .with_feature_names(vec!["id"]);
+        assert!(matches!(
+            wrong_type.transform_record_batch(&batches[0]),
+            Err(ScalerError::UnsupportedColumnType { .. })
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+//! `robust-scaler`: scales records piped through stdin with a saved scaler
+//! (enabled by the `cli` feature).
+//!
+//! ```sh
+//! robust-scaler transform --scaler robust_scaler.json --format csv --header < in.csv > out.csv
+//! ```
+//!
+//! Records are read, scaled and written one line at a time, so memory does not
+//! depend on the size of the input.
+
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+use robust_scaler::{RobustScaler, ScalerError};
+use serde_json::Value;
+
+const USAGE: &str = "\
+Usage: robust-scaler transform --scaler PATH [--format csv|jsonl] [--header]
+
+Reads records from stdin and writes the scaled records to stdout.
+
+Options:
+  --scaler PATH    Scaler saved with `to_json` (or exported from scikit-learn)
+  --format csv     Comma-separated numbers, one record per line (default);
+                   empty fields are missing values
+  --format jsonl   One JSON array of numbers, or one object keyed by feature
+                   name, per line; other keys are passed through
+  --header         With csv, copy the first line through unchanged";
+
+#[derive(Debug, PartialEq)]
+enum Format {
+    Csv,
+    Jsonl,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    scaler: String,
+    format: Format,
+    header: bool,
+}
+
+fn main() -> ExitCode {
+    if std::env::args().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("robust-scaler: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let scaler = match RobustScaler::from_json(&options.scaler) {
+        Ok(scaler) => scaler,
+        Err(e) => {
+            eprintln!("robust-scaler: cannot load '{}': {}", options.scaler, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let output = BufWriter::new(io::stdout().lock());
+    match run(&scaler, &options, io::stdin().lock(), output) {
+        Ok(()) => ExitCode::SUCCESS,
+        // The reader went away (e.g. `| head`): not an error in a pipeline
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("robust-scaler: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the arguments following the program name.
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    match args.next().as_deref() {
+        Some("transform") => {}
+        None => return Err("expected a command".into()),
+        Some(command) => return Err(format!("unknown command '{}'", command)),
+    }
+
+    let mut scaler = None;
+    let mut format = Format::Csv;
+    let mut header = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scaler" => scaler = Some(args.next().ok_or("--scaler needs a path")?),
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("csv") => Format::Csv,
+                    Some("jsonl") => Format::Jsonl,
+                    _ => return Err("--format must be csv or jsonl".into()),
+                }
+            }
+            "--header" => header = true,
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+    }
+
+    Ok(Options {
+        scaler: scaler.ok_or("missing --scaler")?,
+        format,
+        header,
+    })
+}
+
+/// Scales every record of `input` into `output`, stopping at the first invalid one.
+fn run<R: BufRead, W: Write>(
+    scaler: &RobustScaler,
+    options: &Options,
+    input: R,
+    mut output: W,
+) -> io::Result<()> {
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        if i == 0 && options.header && options.format == Format::Csv {
+            writeln!(output, "{}", line)?;
+            continue;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let scaled = match options.format {
+            Format::Csv => scale_csv(scaler, &line),
+            Format::Jsonl => scale_jsonl(scaler, &line),
+        };
+        let scaled = scaled.map_err(|message| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", i + 1, message),
+            )
+        })?;
+        writeln!(output, "{}", scaled)?;
+    }
+    output.flush()
+}
+
+/// Scales one CSV record; missing values are written back as empty fields.
+fn scale_csv(scaler: &RobustScaler, line: &str) -> Result<String, String> {
+    let values = line
+        .split(',')
+        .map(|field| match field.trim() {
+            "" => Ok(f64::NAN),
+            field => field
+                .parse()
+                .map_err(|_| format!("invalid number '{}'", field)),
+        })
+        .collect::<Result<Vec<f64>, _>>()?;
+
+    let scaled: Vec<String> = scale_record(scaler, &values)?
+        .iter()
+        .map(|x| {
+            if x.is_nan() {
+                String::new()
+            } else {
+                x.to_string()
+            }
+        })
+        .collect();
+    Ok(scaled.join(","))
+}
+
+/// Scales one JSON record, an array of numbers or an object keyed by feature
+/// name; missing values (`null` or absent keys) are written as `null`.
+fn scale_jsonl(scaler: &RobustScaler, line: &str) -> Result<String, String> {
+    let number = |value: &Value| match value {
+        Value::Null => Ok(f64::NAN),
+        value => value
+            .as_f64()
+            .ok_or_else(|| format!("expected a number, got {}", value)),
+    };
+
+    let scaled = match serde_json::from_str(line).map_err(|e| e.to_string())? {
+        Value::Array(values) => {
+            let values = values.iter().map(number).collect::<Result<Vec<_>, _>>()?;
+            Value::from(scale_record(scaler, &values)?)
+        }
+        Value::Object(mut record) => {
+            let names = scaler
+                .feature_names()
+                .ok_or("objects need a scaler with feature names")?;
+            let values = names
+                .iter()
+                .map(|name| record.get(name).map_or(Ok(f64::NAN), number))
+                .collect::<Result<Vec<_>, _>>()?;
+            for (name, x) in names.iter().zip(scale_record(scaler, &values)?) {
+                record.insert(name.clone(), x.into());
+            }
+            Value::Object(record)
+        }
+        _ => return Err("expected a JSON array or object".into()),
+    };
+    Ok(scaled.to_string())
+}
+
+/// Scales the values of one record.
+fn scale_record(scaler: &RobustScaler, values: &[f64]) -> Result<Vec<f64>, String> {
+    let mut scaled = scaler.try_transform_rows(&[values]).map_err(record_error)?;
+    Ok(scaled.remove(0))
+}
+
+/// Describes an error without its row index (always 0 for a one-record batch);
+/// the line number is reported instead.
+fn record_error(error: ScalerError) -> String {
+    match error {
+        ScalerError::RowLengthMismatch {
+            expected, found, ..
+        } => format!("{} values but the scaler expects {}", found, expected),
+        ScalerError::MissingValue {
+            column, feature, ..
+        } => format!("missing value in column {}{}", column, name(feature)),
+        ScalerError::NonFiniteValue {
+            column,
+            feature,
+            value,
+            ..
+        } => format!(
+            "non-finite value {} in column {}{}",
+            value,
+            column,
+            name(feature)
+        ),
+        other => other.to_string(),
+    }
+}
+
+/// Formats the feature name of an error, if known, like `ScalerError` does.
+fn name(feature: Option<String>) -> String {
+    feature.map_or_else(String::new, |name| format!(" ('{}')", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(format: Format, header: bool) -> Options {
+        Options {
+            scaler: "s.json".into(),
+            format,
+            header,
+        }
+    }
+
+    fn scale(options: &Options, input: &str) -> io::Result<String> {
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0])
+            .unwrap()
+            .with_feature_names(vec!["a", "b"]);
+        let mut output = Vec::new();
+        run(&scaler, options, input.as_bytes(), &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_cli() {
+        let args = ["transform", "--scaler", "s.json", "--format", "jsonl"];
+        assert_eq!(
+            parse_args(args.iter().map(|a| a.to_string())),
+            Ok(options(Format::Jsonl, false))
+        );
+        assert!(parse_args(["transform".to_string()].into_iter()).is_err());
+
+        let csv = scale(&options(Format::Csv, true), "a,b\n3,6\n\n1,\n").unwrap();
+        assert_eq!(csv, "a,b\n1,1\n0,\n");
+
+        let jsonl = "[3, 6]\n{\"a\": 5, \"b\": null, \"id\": \"x\"}\n";
+        assert_eq!(
+            scale(&options(Format::Jsonl, false), jsonl).unwrap(),
+            "[1.0,1.0]\n{\"a\":2.0,\"b\":null,\"id\":\"x\"}\n"
+        );
+
+        let err = scale(&options(Format::Csv, false), "1,2\n1,2,3\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: 3 values but the scaler expects 2");
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+use ndarray::{Array2, ArrayBase, Data, Ix2};
+use serde::{Deserialize, Serialize};
+
+use crate::{ScalerError, Transformer};
+
+/// Binarizer: maps each value to 1 if it is above a threshold, else 0, as
+/// scikit-learn's `Binarizer`.
+///
+/// The threshold is shared by every feature or given per feature. It is not
+/// learned, so `fit` does nothing; after a `RobustScaler`, the default threshold
+/// of 0 flags the values above the median. NaN stays NaN. Binarizing is not
+/// reversible, so `inverse_transform` is the identity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Binarizer {
+    threshold: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    feature_thresholds: Option<Vec<f64>>, // Overrides `threshold` when set
+}
+
+impl Default for Binarizer {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl Binarizer {
+    /// Creates a binarizer with the same threshold for every feature.
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            feature_thresholds: None,
+        }
+    }
+
+    /// Creates a binarizer with one threshold per feature.
+    pub fn per_feature(thresholds: Vec<f64>) -> Self {
+        Self {
+            threshold: 0.0,
+            feature_thresholds: Some(thresholds),
+        }
+    }
+
+    /// Returns the threshold of a feature.
+    pub fn threshold(&self, feature: usize) -> f64 {
+        match &self.feature_thresholds {
+            Some(thresholds) => thresholds[feature],
+            None => self.threshold,
+        }
+    }
+
+    /// Maps every value above the threshold of its feature to 1, and the others to 0.
+    ///
+    /// # Panics
+    /// If the data does not have one column per threshold. See
+    /// [`Binarizer::try_transform`] for a non-panicking version.
+    pub fn transform<S: Data<Elem = f64>>(&self, data: &ArrayBase<S, Ix2>) -> Array2<f64> {
+        self.try_transform(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Binarizes the data, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// The binarized data, or `ScalerError::FeatureCountMismatch` if the data
+    /// does not have one column per threshold.
+    pub fn try_transform<S: Data<Elem = f64>>(
+        &self,
+        data: &ArrayBase<S, Ix2>,
+    ) -> Result<Array2<f64>, ScalerError> {
+        if let Some(thresholds) = &self.feature_thresholds {
+            if data.ncols() != thresholds.len() {
+                return Err(ScalerError::FeatureCountMismatch {
+                    expected: thresholds.len(),
+                    found: data.ncols(),
+                });
+            }
+        }
+
+        let mut result = data.to_owned();
+        for (j, mut column) in result.columns_mut().into_iter().enumerate() {
+            let threshold = self.threshold(j);
+            column.mapv_inplace(|x| {
+                if x.is_nan() {
+                    x
+                } else if x > threshold {
+                    1.0
+                } else {
+                    0.0
+                }
+            });
+        }
+        Ok(result)
+    }
+}
+
+impl Transformer for Binarizer {
+    fn fit(&mut self, _data: &Array2<f64>) {}
+
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        Binarizer::transform(self, data)
+    }
+
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        data.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_binarizer() {
+        let data = arr2(&[[-1.0, 5.0], [0.0, 10.0], [0.5, f64::NAN]]);
+        let binary = Binarizer::default().transform(&data);
+        assert_eq!(binary.column(0).to_vec(), vec![0.0, 0.0, 1.0]);
+        assert!(binary[[2, 1]].is_nan());
+
+        let per_feature = Binarizer::per_feature(vec![-1.0, 7.0]);
+        let binary = per_feature.transform(&data.slice(ndarray::s![..2, ..]));
+        assert_eq!(binary, arr2(&[[0.0, 0.0], [1.0, 1.0]]));
+        assert_eq!(
+            per_feature.try_transform(&arr2(&[[1.0]])),
+            Err(ScalerError::FeatureCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+
+        #[cfg(feature = "json")]
+        {
+            let step = serde_json::to_value(crate::PipelineStep::from(per_feature)).unwrap();
+            assert_eq!(step["type"], "Binarizer");
+            let loaded: crate::PipelineStep = serde_json::from_value(step).unwrap();
+            assert!(matches!(loaded, crate::PipelineStep::Binarizer(b) if b.threshold(1) == 7.0));
+        }
+    }
+}
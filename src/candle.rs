@@ -0,0 +1,101 @@
+use candle_core::Tensor;
+
+use crate::{MissingPolicy, RobustScaler, ScalerError};
+
+impl RobustScaler {
+    /// Scales a candle tensor of shape `(n_samples, n_features)` on its own
+    /// device (enabled by the `candle` feature).
+    ///
+    /// `center_` and `scale_` are copied to the tensor's device and dtype
+    /// (e.g. `F32` on a GPU) and broadcast over the batch dimension, so the
+    /// data is never copied to the host. The clip bounds are applied, and
+    /// `MissingPolicy::Zero` replaces NaN with 0. `MissingPolicy::Error` would
+    /// need the data on the host: NaN is kept, as with `MissingPolicy::Propagate`.
+    ///
+    /// # Returns
+    /// The scaled tensor, or a candle error if the tensor is not 2D or does not
+    /// have as many columns as the scaler has features.
+    pub fn transform_tensor(&self, tensor: &Tensor) -> candle_core::Result<Tensor> {
+        let (center, scale) = self.tensor_params(tensor)?;
+        let mut scaled = tensor.broadcast_sub(&center)?.broadcast_div(&scale)?;
+        if self.missing_ == MissingPolicy::Zero {
+            // NaN is the only value not equal to itself
+            let is_nan = scaled.ne(&scaled)?;
+            scaled = is_nan.where_cond(&scaled.zeros_like()?, &scaled)?;
+        }
+        match self.clip_ {
+            Some((min, max)) => scaled.clamp(min, max),
+            None => Ok(scaled),
+        }
+    }
+
+    /// Maps a scaled candle tensor back to the original units, on its own device.
+    ///
+    /// # Returns
+    /// The unscaled tensor, or a candle error as in [`RobustScaler::transform_tensor`].
+    pub fn inverse_transform_tensor(&self, tensor: &Tensor) -> candle_core::Result<Tensor> {
+        let (center, scale) = self.tensor_params(tensor)?;
+        tensor.broadcast_mul(&scale)?.broadcast_add(&center)
+    }
+
+    /// Returns `center_` and `scale_` as `(1, n_features)` tensors matching `tensor`.
+    fn tensor_params(&self, tensor: &Tensor) -> candle_core::Result<(Tensor, Tensor)> {
+        let found = tensor.dims2()?.1;
+        if found != self.n_features() {
+            return Err(candle_core::Error::wrap(
+                ScalerError::FeatureCountMismatch {
+                    expected: self.n_features(),
+                    found,
+                },
+            ));
+        }
+        let to_tensor = |values: &[f64]| {
+            Tensor::new(values, tensor.device())?
+                .to_dtype(tensor.dtype())?
+                .unsqueeze(0)
+        };
+        Ok((to_tensor(&self.center_)?, to_tensor(&self.scale_)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_core::{DType, Device};
+    use ndarray::arr2;
+
+    #[test]
+    fn test_transform_tensor() {
+        let data = arr2(&[[1.0, 10.0], [3.0, f64::NAN], [5.0, 30.0]]);
+        let scaler = RobustScaler::from_params(vec![3.0, 20.0], vec![2.0, 10.0])
+            .unwrap()
+            .with_missing_policy(MissingPolicy::Zero)
+            .with_clip(-0.5, 2.0);
+
+        let tensor = Tensor::new(&[[1.0, 10.0], [3.0, f64::NAN], [5.0, 30.0]], &Device::Cpu)
+            .unwrap()
+            .to_dtype(DType::F32)
+            .unwrap();
+        let scaled = scaler.transform_tensor(&tensor).unwrap();
+        assert_eq!(scaled.dtype(), DType::F32);
+        let expected = scaler.transform(&data).mapv(|x| x as f32);
+        assert_eq!(
+            scaled.to_vec2::<f32>().unwrap(),
+            expected
+                .outer_iter()
+                .map(|r| r.to_vec())
+                .collect::<Vec<_>>()
+        );
+
+        let unclipped = RobustScaler::from_params(vec![3.0, 20.0], vec![2.0, 10.0]).unwrap();
+        let round_trip = unclipped
+            .inverse_transform_tensor(&unclipped.transform_tensor(&tensor).unwrap())
+            .unwrap()
+            .to_vec2::<f32>()
+            .unwrap();
+        assert_eq!(round_trip[2], vec![5.0, 30.0]);
+
+        let wrong = Tensor::zeros((2, 3), DType::F32, &Device::Cpu).unwrap();
+        assert!(scaler.transform_tensor(&wrong).is_err());
+    }
+}
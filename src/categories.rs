@@ -0,0 +1,79 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+use ndarray::Array2;
+
+use crate::ScalerError;
+
+/// The categories of each column of a categorical encoder, with the position
+/// of every category in its column's list.
+///
+/// Shared by `OneHotEncoder` and `OrdinalEncoder`, which only differ in how
+/// they turn that position into output values.
+pub(crate) struct Categories<T> {
+    categories: Vec<Vec<T>>,        // Categories of each column, in order
+    index: Vec<BTreeMap<T, usize>>, // Position of each category in `categories`
+}
+
+impl<T: Ord + Clone> Categories<T> {
+    /// Creates an empty list, for an unfitted encoder.
+    pub(crate) fn empty() -> Self {
+        Self {
+            categories: vec![],
+            index: vec![],
+        }
+    }
+
+    /// Indexes known categories, keeping their order.
+    ///
+    /// # Returns
+    /// The indexed categories, or `ScalerError::DuplicateCategory` if a column
+    /// lists the same category twice.
+    pub(crate) fn new(categories: Vec<Vec<T>>) -> Result<Self, ScalerError> {
+        let index = categories
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let index: BTreeMap<T, usize> = column
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .map(|(k, c)| (c, k))
+                    .collect();
+                if index.len() != column.len() {
+                    return Err(ScalerError::DuplicateCategory { column: i });
+                }
+                Ok(index)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { categories, index })
+    }
+
+    /// Learns the sorted unique categories of each column.
+    pub(crate) fn fit(data: &Array2<T>) -> Self {
+        let categories = data
+            .columns()
+            .into_iter()
+            .map(|column| {
+                let unique: BTreeSet<&T> = column.iter().collect();
+                unique.into_iter().cloned().collect()
+            })
+            .collect();
+        Self::new(categories).expect("Categories from a set are unique")
+    }
+
+    /// Returns the position of `value` among the categories of `column`.
+    pub(crate) fn position(&self, column: usize, value: &T) -> Option<usize> {
+        self.index[column].get(value).copied()
+    }
+
+    /// Returns the categories of each column.
+    pub(crate) fn as_slice(&self) -> &[Vec<T>] {
+        &self.categories
+    }
+
+    /// Returns the number of columns.
+    pub(crate) fn len(&self) -> usize {
+        self.categories.len()
+    }
+}
@@ -0,0 +1,176 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use ndarray::Array2;
+
+use crate::{check_fit_values, check_not_empty, RobustScaler, ScalerError};
+
+impl RobustScaler {
+    /// Fits the scaler to named columns, e.g. assembled by feature code.
+    ///
+    /// Features are ordered by name (the map order) and the names are recorded
+    /// as the feature names of the scaler.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    ///
+    /// # Panics
+    /// See [`RobustScaler::try_fit_columns`] for a non-panicking version.
+    pub fn fit_columns(&mut self, columns: &BTreeMap<String, Vec<f64>>) -> &Self {
+        self.try_fit_columns(columns)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fits the scaler to named columns, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// `Ok(&Self)` for chaining, `ScalerError::ColumnLengthMismatch` if the
+    /// columns do not all have the same length, or the errors of
+    /// [`RobustScaler::try_fit`]; the scaler is left untouched on error.
+    pub fn try_fit_columns(
+        &mut self,
+        columns: &BTreeMap<String, Vec<f64>>,
+    ) -> Result<&Self, ScalerError> {
+        let n_samples = check_column_lengths(columns.iter())?;
+        check_not_empty(n_samples, columns.len())?;
+        let names: Vec<String> = columns.keys().cloned().collect();
+        self.check_quantile_range_features(Some(&names), names.len())?;
+        check_fit_values(Some(&names), columns.values())?;
+
+        self.feature_names_ = Some(names);
+        Ok(self.fit_features(columns.values().cloned()))
+    }
+
+    /// Transforms named columns, returning the scaled columns under the same names.
+    ///
+    /// # Panics
+    /// See [`RobustScaler::try_transform_columns_map`] for a non-panicking version.
+    pub fn transform_columns_map(
+        &self,
+        columns: &BTreeMap<String, Vec<f64>>,
+    ) -> BTreeMap<String, Vec<f64>> {
+        self.try_transform_columns_map(columns)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Transforms named columns, returning an error instead of panicking.
+    ///
+    /// Columns are matched to features by name when the scaler knows its
+    /// feature names, and taken in name order otherwise.
+    ///
+    /// # Returns
+    /// The scaled columns, `ScalerError::MissingFeature` or
+    /// `ScalerError::UnknownFeature` if the names do not match the features,
+    /// `ScalerError::ColumnLengthMismatch`, or any error of [`RobustScaler::try_transform`].
+    pub fn try_transform_columns_map(
+        &self,
+        columns: &BTreeMap<String, Vec<f64>>,
+    ) -> Result<BTreeMap<String, Vec<f64>>, ScalerError> {
+        let ordered: Vec<(&String, &Vec<f64>)> = match &self.feature_names_ {
+            Some(names) => {
+                if let Some(name) = columns.keys().find(|name| !names.contains(name)) {
+                    return Err(ScalerError::UnknownFeature { name: name.clone() });
+                }
+                names
+                    .iter()
+                    .map(|name| {
+                        columns
+                            .get_key_value(name)
+                            .ok_or_else(|| ScalerError::MissingFeature {
+                                name: name.to_string(),
+                            })
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+            None => columns.iter().collect(),
+        };
+        let n_samples = check_column_lengths(ordered.iter().copied())?;
+
+        let data = Array2::from_shape_fn((n_samples, ordered.len()), |(row, column)| {
+            ordered[column].1[row]
+        });
+        let scaled = self.try_transform(&data)?;
+
+        Ok(ordered
+            .iter()
+            .zip(scaled.columns())
+            .map(|((name, _), column)| (name.to_string(), column.to_vec()))
+            .collect())
+    }
+}
+
+/// Checks that all the columns have the same length, and returns it.
+fn check_column_lengths<'a, I>(columns: I) -> Result<usize, ScalerError>
+where
+    I: IntoIterator<Item = (&'a String, &'a Vec<f64>)>,
+{
+    let mut columns = columns.into_iter();
+    let expected = columns.next().map_or(0, |(_, values)| values.len());
+    match columns.find(|(_, values)| values.len() != expected) {
+        Some((name, values)) => Err(ScalerError::ColumnLengthMismatch {
+            name: name.clone(),
+            expected,
+            found: values.len(),
+        }),
+        None => Ok(expected),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn columns(entries: &[(&str, Vec<f64>)]) -> BTreeMap<String, Vec<f64>> {
+        entries
+            .iter()
+            .map(|(name, values)| (name.to_string(), values.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_columns() {
+        let mut scaler = RobustScaler::new();
+        scaler.fit_columns(&columns(&[
+            ("price", vec![10.0, 20.0, 30.0]),
+            ("age", vec![1.0, 2.0, 3.0]),
+        ]));
+
+        assert_eq!(
+            scaler.feature_names(),
+            Some(&["age".to_string(), "price".to_string()][..])
+        );
+        assert_eq!(scaler.center(), &[2.0, 20.0]);
+
+        let scaled =
+            scaler.transform_columns_map(&columns(&[("age", vec![3.0]), ("price", vec![10.0])]));
+        assert_eq!(
+            scaled,
+            columns(&[("age", vec![1.0]), ("price", vec![-1.0])])
+        );
+
+        let ragged = columns(&[("a", vec![1.0, 2.0]), ("b", vec![1.0])]);
+        assert_eq!(
+            RobustScaler::new().try_fit_columns(&ragged).err(),
+            Some(ScalerError::ColumnLengthMismatch {
+                name: "b".to_string(),
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            scaler.try_transform_columns_map(&columns(&[("age", vec![1.0])])),
+            Err(ScalerError::MissingFeature {
+                name: "price".to_string()
+            })
+        );
+        assert!(matches!(
+            scaler.try_transform_columns_map(&columns(&[
+                ("age", vec![1.0]),
+                ("price", vec![1.0]),
+                ("height", vec![1.0]),
+            ])),
+            Err(ScalerError::UnknownFeature { .. })
+        ));
+    }
+}
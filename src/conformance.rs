@@ -0,0 +1,239 @@
+//! Golden-file checks against scikit-learn.
+//!
+//! A golden directory holds one JSON file per case:
+//!
+//! ```json
+//! {
+//!   "input": [[1.0, 2.0], [3.0, 4.0]],
+//!   "expected": [[-1.0, -1.0], [1.0, 1.0]],
+//!   "params": {"center_": [2.0, 3.0], "scale_": [1.0, 1.0], "n_features_in_": 2}
+//! }
+//! ```
+//!
+//! where `params` uses the schema of [`RobustScaler::from_json`] and
+//! `expected` is `scaler.transform(input)` computed by scikit-learn. A case
+//! passes if transforming `input` with `params` reproduces `expected`, and
+//! fitting a new scaler on `input` reproduces `params`. Set
+//! `"fitted_on_input": false` for cases whose parameters were fitted on other data.
+//!
+//! Cases can be generated from Python with:
+//!
+//! ```python
+//! scaler = RobustScaler().fit(X)
+//! json.dump({
+//!     "input": X.tolist(),
+//!     "expected": scaler.transform(X).tolist(),
+//!     "params": {
+//!         "center_": scaler.center_.tolist(),
+//!         "scale_": scaler.scale_.tolist(),
+//!         "n_features_in_": scaler.n_features_in_,
+//!     },
+//! }, open("golden/case.json", "w"))
+//! ```
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use ndarray::Array2;
+use serde::Deserialize;
+
+use crate::format::ScalerFile;
+use crate::{invalid_data, RobustScaler};
+
+/// A single golden case: an input matrix, the scikit-learn output and parameters.
+pub struct GoldenCase {
+    /// File stem of the case, used in failure messages.
+    pub name: String,
+    /// Data passed to scikit-learn's `fit` and `transform`.
+    pub input: Array2<f64>,
+    /// Output of scikit-learn's `transform`.
+    pub expected: Array2<f64>,
+    /// Scaler built from the scikit-learn parameters.
+    pub params: RobustScaler,
+    /// Whether `params` were fitted on `input`.
+    pub fitted_on_input: bool,
+}
+
+#[derive(Deserialize)]
+struct GoldenFile {
+    input: Vec<Vec<f64>>,
+    expected: Vec<Vec<f64>>,
+    params: serde_json::Value,
+    #[serde(default = "default_fitted_on_input")]
+    fitted_on_input: bool,
+}
+
+fn default_fitted_on_input() -> bool {
+    true
+}
+
+impl GoldenCase {
+    /// Loads a golden case from a JSON file.
+    ///
+    /// # Returns
+    /// `Ok(GoldenCase)` if successful, `Err` if the file cannot be read, is not
+    /// a valid case, or its matrices are ragged.
+    pub fn load<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let reader = BufReader::new(File::open(path)?);
+        let file: GoldenFile = serde_json::from_reader(reader).map_err(invalid_data)?;
+
+        Ok(Self {
+            name: path
+                .file_stem()
+                .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned()),
+            input: to_array(file.input).map_err(invalid_data)?,
+            expected: to_array(file.expected).map_err(invalid_data)?,
+            params: ScalerFile::from_value(file.params)?.into_scaler()?,
+            fitted_on_input: file.fitted_on_input,
+        })
+    }
+
+    /// Checks the Rust implementation against the case.
+    ///
+    /// Values match when `|a - b| <= atol + rtol * |b|`, with `b` from scikit-learn;
+    /// NaN matches NaN.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `Err` with a description of the first mismatch.
+    pub fn check(&self, rtol: f64, atol: f64) -> Result<(), String> {
+        let output = self
+            .params
+            .try_transform(&self.input)
+            .map_err(|e| format!("{}: transform failed: {}", self.name, e))?;
+        if output.dim() != self.expected.dim() {
+            return Err(format!(
+                "{}: output shape {:?} does not match expected {:?}",
+                self.name,
+                output.dim(),
+                self.expected.dim()
+            ));
+        }
+        let mismatch = output
+            .indexed_iter()
+            .find(|&(index, &a)| !is_close(a, self.expected[index], rtol, atol));
+        if let Some(((row, column), &value)) = mismatch {
+            return Err(format!(
+                "{}: output at row {}, column {} is {}, expected {}",
+                self.name,
+                row,
+                column,
+                value,
+                self.expected[[row, column]]
+            ));
+        }
+
+        if self.fitted_on_input {
+            let (q_min, q_max) = self.params.quantile_range();
+            let mut fitted = RobustScaler::new()
+                .with_quantile_range(q_min, q_max)
+                .with_centering(self.params.centering())
+                .with_scaling(self.params.scaling())
+                .with_unit_variance(self.params.unit_variance());
+            fitted.fit(&self.input);
+            let diff = fitted.diff(&self.params, rtol, atol);
+            if !diff.is_match() {
+                return Err(format!("{}: fitted parameters differ\n{}", self.name, diff));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Loads every `*.json` case in a directory, sorted by name.
+///
+/// # Returns
+/// `Ok(Vec<GoldenCase>)`, or the first error from [`GoldenCase::load`].
+pub fn load_dir<P: AsRef<Path>>(dir: P) -> std::io::Result<Vec<GoldenCase>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths.into_iter().map(GoldenCase::load).collect()
+}
+
+/// Checks every case in a golden directory, e.g. from an integration test.
+///
+/// # Panics
+/// If the directory cannot be loaded, contains no cases, or any case fails;
+/// the message lists all failing cases.
+pub fn assert_conformance<P: AsRef<Path>>(dir: P, rtol: f64, atol: f64) {
+    let dir = dir.as_ref();
+    let cases = load_dir(dir)
+        .unwrap_or_else(|e| panic!("Failed to load golden cases from {}: {}", dir.display(), e));
+    assert!(!cases.is_empty(), "No golden cases in {}", dir.display());
+
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|case| case.check(rtol, atol).err())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} golden cases failed:\n{}",
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+}
+
+fn is_close(a: f64, b: f64, rtol: f64, atol: f64) -> bool {
+    (a.is_nan() && b.is_nan()) || (a - b).abs() <= atol + rtol * b.abs()
+}
+
+fn to_array(rows: Vec<Vec<f64>>) -> Result<Array2<f64>, &'static str> {
+    let n_columns = rows.first().map_or(0, Vec::len);
+    if rows.iter().any(|row| row.len() != n_columns) {
+        return Err("Rows of a golden matrix must have the same length");
+    }
+    Array2::from_shape_vec((rows.len(), n_columns), rows.concat())
+        .map_err(|_| "Invalid golden matrix")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conformance() {
+        let dir = std::env::temp_dir().join("robust_scaler_test_conformance");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("basic.json"),
+            r#"{
+                "input": [[1.0, 10.0], [2.0, 20.0], [3.0, 30.0], [4.0, 40.0], [5.0, 50.0]],
+                "expected": [[-1.0, -1.0], [-0.5, -0.5], [0.0, 0.0], [0.5, 0.5], [1.0, 1.0]],
+                "params": {"center_": [3.0, 30.0], "scale_": [2.0, 20.0], "n_features_in_": 2}
+            }"#,
+        )
+        .unwrap();
+        assert_conformance(&dir, 1e-12, 0.0);
+
+        std::fs::write(
+            dir.join("wrong.json"),
+            r#"{
+                "input": [[1.0], [3.0]],
+                "expected": [[0.0], [2.0]],
+                "params": {"center_": [1.0], "scale_": [2.0], "n_features_in_": 1},
+                "fitted_on_input": false
+            }"#,
+        )
+        .unwrap();
+        let cases = load_dir(&dir).unwrap();
+        assert_eq!(cases.len(), 2);
+        assert!(cases[0].check(1e-12, 0.0).is_ok());
+        let error = cases[1].check(1e-12, 0.0).unwrap_err();
+        assert!(error.starts_with("wrong: output at row 1, column 0"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::RobustScaler;
+
+/// Differences between the parameters of two scalers for a single feature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureDiff {
+    /// `|center - other center|`.
+    pub center_abs: f64,
+    /// `|center - other center| / max(|center|, |other center|)`, or 0 if both are equal.
+    pub center_rel: f64,
+    /// `|scale - other scale|`.
+    pub scale_abs: f64,
+    /// `|scale - other scale| / max(|scale|, |other scale|)`, or 0 if both are equal.
+    pub scale_rel: f64,
+    /// Whether both center and scale are within tolerance.
+    pub matches: bool,
+}
+
+/// Per-feature comparison of two scalers, e.g. one fitted in Rust and one
+/// exported from scikit-learn.
+///
+/// Prints as a table of the mismatching features, for CI logs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalerDiff {
+    /// Number of features of both scalers; features are only compared if they match.
+    pub n_features: (usize, usize),
+    /// One entry per feature, in column order.
+    pub features: Vec<FeatureDiff>,
+}
+
+impl ScalerDiff {
+    /// Returns `true` if both scalers have the same features and all are within tolerance.
+    pub fn is_match(&self) -> bool {
+        self.n_features.0 == self.n_features.1 && self.features.iter().all(|f| f.matches)
+    }
+
+    /// Returns the indices of the features outside tolerance.
+    pub fn mismatched_features(&self) -> Vec<usize> {
+        self.features
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.matches)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl fmt::Display for ScalerDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (n, other) = self.n_features;
+        if n != other {
+            return write!(f, "Scalers have {} and {} features", n, other);
+        }
+        if self.is_match() {
+            return write!(f, "Scalers match on all {} features", n);
+        }
+
+        writeln!(
+            f,
+            "{:>8} {:>12} {:>12} {:>12} {:>12}",
+            "feature", "center_abs", "center_rel", "scale_abs", "scale_rel"
+        )?;
+        for i in self.mismatched_features() {
+            let d = &self.features[i];
+            writeln!(
+                f,
+                "{:>8} {:>12.3e} {:>12.3e} {:>12.3e} {:>12.3e}",
+                i, d.center_abs, d.center_rel, d.scale_abs, d.scale_rel
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl RobustScaler {
+    /// Compares the fitted parameters with those of `other`.
+    ///
+    /// A value matches when `|a - b| <= atol + rtol * max(|a|, |b|)`, so the
+    /// result does not depend on which scaler is `other`.
+    ///
+    /// # Returns
+    /// A `ScalerDiff`; `assert!(diff.is_match(), "{}", diff)` in tests.
+    pub fn diff(&self, other: &RobustScaler, rtol: f64, atol: f64) -> ScalerDiff {
+        let n_features = (self.n_features(), other.n_features());
+        if n_features.0 != n_features.1 {
+            return ScalerDiff {
+                n_features,
+                features: Vec::new(),
+            };
+        }
+
+        let close = |a: f64, b: f64| (a - b).abs() <= atol + rtol * a.abs().max(b.abs());
+        let features = (0..self.n_features())
+            .map(|i| {
+                let (center, other_center) = (self.center_[i], other.center_[i]);
+                let (scale, other_scale) = (self.scale_[i], other.scale_[i]);
+                FeatureDiff {
+                    center_abs: (center - other_center).abs(),
+                    center_rel: relative_diff(center, other_center),
+                    scale_abs: (scale - other_scale).abs(),
+                    scale_rel: relative_diff(scale, other_scale),
+                    matches: close(center, other_center) && close(scale, other_scale),
+                }
+            })
+            .collect();
+
+        ScalerDiff {
+            n_features,
+            features,
+        }
+    }
+}
+
+/// Returns `|a - b| / max(|a|, |b|)`, defined as 0 when `a == b` (e.g. both 0).
+fn relative_diff(a: f64, b: f64) -> f64 {
+    let abs = (a - b).abs();
+    if abs == 0.0 {
+        0.0
+    } else {
+        abs / a.abs().max(b.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let a = RobustScaler::from_params(vec![1.0, 2.0], vec![1.0, 4.0]).unwrap();
+        let b = RobustScaler::from_params(vec![1.0 + 1e-12, 2.5], vec![1.0, 4.0]).unwrap();
+
+        let diff = a.diff(&b, 1e-9, 0.0);
+        assert!(!diff.is_match());
+        assert_eq!(diff.mismatched_features(), vec![1]);
+        assert_eq!(diff.features[1].center_abs, 0.5);
+        assert!(diff.to_string().contains("center_abs"));
+
+        assert!(a.diff(&a, 0.0, 0.0).is_match());
+        let c = RobustScaler::from_params(vec![1.0], vec![1.0]).unwrap();
+        assert!(!a.diff(&c, 1e-9, 0.0).is_match());
+
+        // Uncentered scalers have a center of 0 on both sides
+        let d = RobustScaler::from_params(vec![0.0], vec![2.0]).unwrap();
+        let e = RobustScaler::from_params(vec![0.0], vec![2.0 + 1e-12]).unwrap();
+        let diff = d.diff(&e, 1e-9, 0.0);
+        assert_eq!(diff.features[0].center_rel, 0.0);
+        assert!(diff.is_match() && e.diff(&d, 1e-9, 0.0).is_match());
+        let f = RobustScaler::from_params(vec![1.0], vec![2.0]).unwrap();
+        assert_eq!(d.diff(&f, 0.0, 0.0).features[0].center_rel, 1.0);
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::vec::Vec;
+use ndarray::{ArrayBase, Data, Ix2};
+
+use crate::{check_fit_values, check_not_empty, median_sorted, sorted, RobustScaler, ScalerError};
+
+/// Thresholds above which a feature is flagged as drifted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DriftThresholds {
+    /// Maximum allowed shift of the median, in units of the fitted scale.
+    pub center_shift: f64,
+    /// Maximum allowed ratio between the new and the fitted scale (or its inverse).
+    pub scale_ratio: f64,
+}
+
+impl Default for DriftThresholds {
+    fn default() -> Self {
+        Self {
+            center_shift: 0.5,
+            scale_ratio: 2.0,
+        }
+    }
+}
+
+/// Drift statistics of a single feature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureDrift {
+    /// Median of the feature on the new batch.
+    pub center: f64,
+    /// Spread of the feature on the new batch, measured like the fitted scale.
+    pub scale: f64,
+    /// `|new median - fitted median| / fitted scale`.
+    pub center_shift: f64,
+    /// `new scale / fitted scale`.
+    pub scale_ratio: f64,
+    /// Whether any of the thresholds was exceeded.
+    pub drifted: bool,
+}
+
+/// Per-feature comparison between a new batch and the fitted statistics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriftReport {
+    /// One entry per feature, in column order.
+    pub features: Vec<FeatureDrift>,
+}
+
+impl DriftReport {
+    /// Returns `true` if at least one feature drifted.
+    pub fn has_drift(&self) -> bool {
+        self.features.iter().any(|f| f.drifted)
+    }
+
+    /// Returns the indices of the features that drifted.
+    pub fn drifted_features(&self) -> Vec<usize> {
+        self.features
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.drifted)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl RobustScaler {
+    /// Compares a new batch against the fitted statistics using the default thresholds.
+    ///
+    /// # Arguments
+    /// * `data` - A 2D array or view with the same number of features as the scaler.
+    ///
+    /// # Returns
+    /// A `DriftReport` with per-feature shift scores.
+    ///
+    /// # Panics
+    /// See [`RobustScaler::try_check_drift_with_thresholds`].
+    pub fn check_drift<S: Data<Elem = f64>>(&self, data: &ArrayBase<S, Ix2>) -> DriftReport {
+        self.check_drift_with_thresholds(data, &DriftThresholds::default())
+    }
+
+    /// Compares a new batch against the fitted statistics.
+    ///
+    /// Recomputes the median and scale (with the fitted `ScaleEstimator`) of every
+    /// column of `data` and flags the features whose median moved by more than
+    /// `thresholds.center_shift` fitted scales, or whose scale grew or shrank by
+    /// more than `thresholds.scale_ratio`. The fitted median comes from
+    /// [`RobustScaler::statistics`], so it is compared even when the scaler was
+    /// fitted with `with_centering(false)`; a scaler without statistics (e.g.
+    /// built with `from_params`) uses `center_`.
+    ///
+    /// # Panics
+    /// If [`RobustScaler::try_check_drift_with_thresholds`] fails.
+    pub fn check_drift_with_thresholds<S: Data<Elem = f64>>(
+        &self,
+        data: &ArrayBase<S, Ix2>,
+        thresholds: &DriftThresholds,
+    ) -> DriftReport {
+        self.try_check_drift_with_thresholds(data, thresholds)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Compares a new batch against the fitted statistics, returning an error
+    /// instead of panicking.
+    ///
+    /// # Returns
+    /// The `DriftReport`, `ScalerError::FeatureCountMismatch` if the batch does
+    /// not have one column per feature, `ScalerError::EmptyInput` if it has no
+    /// rows, or `ScalerError::MissingValue` / `ScalerError::NonFiniteValue` for
+    /// the first NaN or infinite value.
+    pub fn try_check_drift_with_thresholds<S: Data<Elem = f64>>(
+        &self,
+        data: &ArrayBase<S, Ix2>,
+        thresholds: &DriftThresholds,
+    ) -> Result<DriftReport, ScalerError> {
+        if data.ncols() != self.n_features() {
+            return Err(ScalerError::FeatureCountMismatch {
+                expected: self.n_features(),
+                found: data.ncols(),
+            });
+        }
+        check_not_empty(data.nrows(), data.ncols())?;
+        check_fit_values(self.feature_names(), data.columns())?;
+
+        let features = self
+            .scale()
+            .iter()
+            .enumerate()
+            .map(|(i, &fitted_scale)| {
+                let fitted_center = self
+                    .statistics()
+                    .map_or(self.center()[i], |statistics| statistics[i].median);
+                let feature = sorted(&data.column(i));
+                let center = median_sorted(&feature);
+                let scale = self.feature_scale(i, &feature);
+
+                let center_shift = (center - fitted_center).abs() / fitted_scale;
+                let scale_ratio = scale / fitted_scale;
+                let drifted = center_shift > thresholds.center_shift
+                    || scale_ratio > thresholds.scale_ratio
+                    || scale_ratio < 1.0 / thresholds.scale_ratio;
+
+                FeatureDrift {
+                    center,
+                    scale,
+                    center_shift,
+                    scale_ratio,
+                    drifted,
+                }
+            })
+            .collect();
+
+        Ok(DriftReport { features })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr2, Array2};
+
+    #[test]
+    fn test_check_drift() {
+        let mut scaler = RobustScaler::new();
+        scaler.fit(&arr2(&[[1.0, 10.0], [3.0, 20.0], [5.0, 30.0]]));
+
+        // Feature 0 keeps its distribution, feature 1 shifts by 50 (5 IQRs).
+        let report = scaler.check_drift(&arr2(&[[1.0, 60.0], [3.0, 70.0], [5.0, 80.0]]));
+        assert_eq!(report.drifted_features(), vec![1]);
+        assert!(report.has_drift());
+        assert!((report.features[1].center_shift - 5.0).abs() < 1e-12);
+        assert!((report.features[0].scale_ratio - 1.0).abs() < 1e-12);
+
+        // Without centering, the shift is measured from the fitted median, not 0
+        let mut uncentered = RobustScaler::new().with_centering(false);
+        uncentered.fit(&arr2(&[[100.0], [102.0], [104.0]]));
+        let unchanged = arr2(&[[100.0], [102.0], [104.0]]);
+        assert!(!uncentered.check_drift(&unchanged.view()).has_drift());
+
+        assert_eq!(
+            scaler.try_check_drift_with_thresholds(
+                &Array2::zeros((0, 2)),
+                &DriftThresholds::default()
+            ),
+            Err(ScalerError::EmptyInput {
+                n_samples: 0,
+                n_features: 2
+            })
+        );
+    }
+}
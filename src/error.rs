@@ -0,0 +1,199 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Errors returned when building or applying a `RobustScaler`.
+///
+/// Errors about input data carry the row and column of the offending value,
+/// plus the feature name when the scaler knows it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScalerError {
+    /// `center` and `scale` do not have the same number of features.
+    LengthMismatch { center: usize, scale: usize },
+    /// A center value is NaN or infinite.
+    InvalidCenter { feature: usize, value: f64 },
+    /// A scale value is not a finite, strictly positive number.
+    InvalidScale { feature: usize, value: f64 },
+    /// The data does not have as many features as the scaler.
+    FeatureCountMismatch { expected: usize, found: usize },
+    /// A row does not have as many values as the scaler has features.
+    RowLengthMismatch {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A NaN was found while the missing-value policy is `MissingPolicy::Error`.
+    MissingValue {
+        row: usize,
+        column: usize,
+        feature: Option<String>,
+    },
+    /// An infinite value was found in the data.
+    NonFiniteValue {
+        row: usize,
+        column: usize,
+        feature: Option<String>,
+        value: f64,
+    },
+    /// The data to fit on has no samples or no features.
+    EmptyInput { n_samples: usize, n_features: usize },
+    /// The progress callback of `fit_with_progress` cancelled the fit.
+    Cancelled { done: usize, total: usize },
+    /// A shard passed to `RobustScaler::merge` did not retain its samples.
+    NoRetainedSamples { shard: usize },
+    /// The scaler has no fitted parameters.
+    NotFitted,
+    /// A feature column does not hold `f64` values.
+    UnsupportedColumnType { name: String, data_type: String },
+    /// A feature index is not below the number of features.
+    FeatureIndexOutOfRange { index: usize, n_features: usize },
+    /// A feature name is not known to the scaler.
+    UnknownFeature { name: String },
+    /// A feature of the scaler is missing from named columns.
+    MissingFeature { name: String },
+    /// A named column is not as long as the other columns.
+    ColumnLengthMismatch {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A category was not seen when the encoder was fitted.
+    UnknownCategory { row: usize, column: usize },
+    /// A row belongs to a group that was not seen when the scaler was fitted.
+    UnknownGroup { row: usize },
+    /// A list of known categories repeats a category.
+    DuplicateCategory { column: usize },
+    /// A serialized scaler was written by a newer version of this crate.
+    UnsupportedFormatVersion { found: u64 },
+    /// A serialized scaler could not be parsed or is inconsistent.
+    InvalidFile { message: String },
+}
+
+impl fmt::Display for ScalerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScalerError::LengthMismatch { center, scale } => write!(
+                f,
+                "Length of center ({}) does not match length of scale ({})",
+                center, scale
+            ),
+            ScalerError::InvalidCenter { feature, value } => {
+                write!(f, "Center of feature {} is not finite: {}", feature, value)
+            }
+            ScalerError::InvalidScale { feature, value } => write!(
+                f,
+                "Scale of feature {} must be finite and positive, got {}",
+                feature, value
+            ),
+            ScalerError::FeatureCountMismatch { expected, found } => write!(
+                f,
+                "Input has {} features but the scaler expects {}",
+                found, expected
+            ),
+            ScalerError::RowLengthMismatch {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} values but the scaler expects {}",
+                row, found, expected
+            ),
+            ScalerError::MissingValue {
+                row,
+                column,
+                feature,
+            } => {
+                write!(f, "Missing value at row {}, column {}", row, column)?;
+                write_feature_name(f, feature)
+            }
+            ScalerError::NonFiniteValue {
+                row,
+                column,
+                feature,
+                value,
+            } => {
+                write!(
+                    f,
+                    "Non-finite value {} at row {}, column {}",
+                    value, row, column
+                )?;
+                write_feature_name(f, feature)
+            }
+            ScalerError::Cancelled { done, total } => {
+                write!(f, "Fit cancelled after {} of {} features", done, total)
+            }
+            ScalerError::NoRetainedSamples { shard } => write!(
+                f,
+                "Shard {} has no retained samples: fit it with `with_retain_samples(true)`",
+                shard
+            ),
+            ScalerError::NotFitted => write!(f, "The scaler is not fitted"),
+            ScalerError::UnsupportedColumnType { name, data_type } => write!(
+                f,
+                "Column '{}' has type {} but features must be Float64",
+                name, data_type
+            ),
+            ScalerError::FeatureIndexOutOfRange { index, n_features } => write!(
+                f,
+                "Feature index {} is out of range for {} features",
+                index, n_features
+            ),
+            ScalerError::UnknownFeature { name } => write!(f, "Unknown feature '{}'", name),
+            ScalerError::MissingFeature { name } => write!(f, "Missing feature '{}'", name),
+            ScalerError::ColumnLengthMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Column '{}' has {} values but the other columns have {}",
+                name, found, expected
+            ),
+            ScalerError::UnknownCategory { row, column } => {
+                write!(f, "Unknown category at row {}, column {}", row, column)
+            }
+            ScalerError::UnknownGroup { row } => {
+                write!(
+                    f,
+                    "Row {} belongs to a group that was not seen during fit",
+                    row
+                )
+            }
+            ScalerError::DuplicateCategory { column } => {
+                write!(f, "Column {} lists the same category twice", column)
+            }
+            ScalerError::EmptyInput {
+                n_samples,
+                n_features,
+            } => write!(
+                f,
+                "Cannot fit on empty data: {} samples, {} features",
+                n_samples, n_features
+            ),
+            ScalerError::UnsupportedFormatVersion { found } => write!(
+                f,
+                "Scaler file has format_version {} but at most {} is supported; \
+                 upgrade robust_scaler to load it",
+                found,
+                crate::FORMAT_VERSION
+            ),
+            ScalerError::InvalidFile { message } => write!(f, "Invalid scaler file: {}", message),
+        }
+    }
+}
+
+fn write_feature_name(f: &mut fmt::Formatter<'_>, feature: &Option<String>) -> fmt::Result {
+    match feature {
+        Some(name) => write!(f, " ('{}')", name),
+        None => Ok(()),
+    }
+}
+
+impl core::error::Error for ScalerError {}
+
+#[cfg(feature = "std")]
+impl From<ScalerError> for std::io::Error {
+    fn from(err: ScalerError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
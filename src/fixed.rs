@@ -0,0 +1,162 @@
+use crate::{check_params, MissingPolicy, RobustScaler, ScalerError};
+
+/// A fitted `RobustScaler` with a number of features fixed at compile time.
+///
+/// Parameters live in `[f64; N]` arrays, so `transform` never allocates and
+/// passing a row of the wrong length is a type error. Build one from a fitted
+/// (or loaded) `RobustScaler` with `RobustScalerConst::try_from(&scaler)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RobustScalerConst<const N: usize> {
+    center: [f64; N],
+    scale: [f64; N],
+    clip: Option<(f64, f64)>,
+    missing: MissingPolicy,
+}
+
+impl<const N: usize> RobustScalerConst<N> {
+    /// Creates a scaler from known center and scale values.
+    ///
+    /// # Returns
+    /// `Ok(RobustScalerConst)`, or `Err` under the same conditions as
+    /// [`RobustScaler::from_params`].
+    pub fn from_params(center: [f64; N], scale: [f64; N]) -> Result<Self, ScalerError> {
+        check_params(&center, &scale)?;
+        Ok(Self {
+            center,
+            scale,
+            clip: None,
+            missing: MissingPolicy::default(),
+        })
+    }
+
+    /// Scales a single row: (x - center) / scale.
+    ///
+    /// Applies the clip range and the `MissingPolicy::Zero` policy of the
+    /// source scaler, but does not validate the input; use
+    /// [`RobustScalerConst::try_transform`] to reject missing or infinite values.
+    pub fn transform(&self, row: &[f64; N]) -> [f64; N] {
+        core::array::from_fn(|i| self.scale_value(row[i], i))
+    }
+
+    /// Validates and scales a single row.
+    ///
+    /// # Returns
+    /// `Ok([f64; N])`, or `Err` for the first missing (under `MissingPolicy::Error`)
+    /// or infinite value.
+    pub fn try_transform(&self, row: &[f64; N]) -> Result<[f64; N], ScalerError> {
+        for (column, &x) in row.iter().enumerate() {
+            if x.is_nan() && self.missing == MissingPolicy::Error {
+                return Err(ScalerError::MissingValue {
+                    row: 0,
+                    column,
+                    feature: None,
+                });
+            }
+            if x.is_infinite() {
+                return Err(ScalerError::NonFiniteValue {
+                    row: 0,
+                    column,
+                    feature: None,
+                    value: x,
+                });
+            }
+        }
+        Ok(self.transform(row))
+    }
+
+    /// Reverts the scaling of a single row: x * scale + center.
+    pub fn inverse_transform(&self, row: &[f64; N]) -> [f64; N] {
+        core::array::from_fn(|i| row[i] * self.scale[i] + self.center[i])
+    }
+
+    /// Returns the center (median) of each feature.
+    pub fn center(&self) -> &[f64; N] {
+        &self.center
+    }
+
+    /// Returns the scale (IQR) of each feature.
+    pub fn scale(&self) -> &[f64; N] {
+        &self.scale
+    }
+
+    fn scale_value(&self, x: f64, i: usize) -> f64 {
+        let scaled = if x.is_nan() && self.missing == MissingPolicy::Zero {
+            0.0
+        } else {
+            (x - self.center[i]) / self.scale[i]
+        };
+        match self.clip {
+            Some((min, max)) => scaled.clamp(min, max),
+            None => scaled,
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<&RobustScaler> for RobustScalerConst<N> {
+    type Error = ScalerError;
+
+    /// Copies the parameters of a dynamic scaler with exactly `N` features,
+    /// checking them as [`RobustScaler::from_params`] does.
+    fn try_from(scaler: &RobustScaler) -> Result<Self, ScalerError> {
+        let mismatch = || ScalerError::FeatureCountMismatch {
+            expected: N,
+            found: scaler.n_features(),
+        };
+        let mut fixed = Self::from_params(
+            scaler.center().try_into().map_err(|_| mismatch())?,
+            scaler.scale().try_into().map_err(|_| mismatch())?,
+        )?;
+        fixed.clip = scaler.clip();
+        fixed.missing = scaler.missing_policy();
+        Ok(fixed)
+    }
+}
+
+impl<const N: usize> From<RobustScalerConst<N>> for RobustScaler {
+    fn from(scaler: RobustScalerConst<N>) -> Self {
+        // Every constructor of `RobustScalerConst` checks the parameters
+        RobustScaler {
+            center_: scaler.center.to_vec(),
+            scale_: scaler.scale.to_vec(),
+            clip_: scaler.clip,
+            missing_: scaler.missing,
+            ..RobustScaler::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const_scaler() {
+        let dynamic = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0])
+            .unwrap()
+            .with_clip(-1.0, 1.0);
+        let scaler = RobustScalerConst::<2>::try_from(&dynamic).unwrap();
+
+        assert_eq!(scaler.transform(&[3.0, 100.0]), [1.0, 1.0]);
+        assert_eq!(scaler.inverse_transform(&[1.0, 0.5]), [3.0, 4.0]);
+        assert!(scaler.try_transform(&[f64::INFINITY, 0.0]).is_err());
+        assert_eq!(RobustScaler::from(scaler).clip(), Some((-1.0, 1.0)));
+
+        assert_eq!(
+            RobustScalerConst::<3>::try_from(&dynamic),
+            Err(ScalerError::FeatureCountMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let mut invalid = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0]).unwrap();
+        invalid.scale_[1] = 0.0;
+        assert_eq!(
+            RobustScalerConst::<2>::try_from(&invalid),
+            Err(ScalerError::InvalidScale {
+                feature: 1,
+                value: 0.0
+            })
+        );
+    }
+}
@@ -0,0 +1,474 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::range::{is_valid_quantile_range, QuantileRangeOverride, DEFAULT_QUANTILE_RANGE};
+use crate::{
+    FeatureStatistics, MissingPolicy, RobustScaler, ScaleEstimator, ScalerError, ScalerMetadata,
+};
+
+/// Version of the file format written by [`RobustScaler::to_json`].
+///
+/// - Version 1: scikit-learn's flat export (`center_`, `scale_`, `n_features_in_`),
+///   without a `format_version` field.
+/// - Version 2: adds `format_version` and groups the Rust-side settings under `options`.
+/// - Version 3: adds `options.feature_quantile_ranges`, `options.scale_estimator`,
+///   `options.subsample`, `options.retain_samples`, `options.save_statistics`,
+///   `samples_` and `metadata`, and rejects unknown fields, so a reader fails on
+///   a field it does not know instead of loading a differently configured
+///   scaler. A version 2 file is a version 3 file without the new fields.
+pub const FORMAT_VERSION: u32 = 3;
+
+/// Current (version 3) on-disk representation of a scaler.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScalerFile {
+    format_version: u32,
+
+    #[serde(rename = "center_")]
+    center: Vec<f64>,
+
+    #[serde(rename = "scale_")]
+    scale: Vec<f64>,
+
+    #[serde(rename = "n_features_in_")]
+    n_features_in: usize,
+
+    #[serde(
+        rename = "feature_names_in_",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    feature_names: Option<Vec<String>>,
+
+    #[serde(default)]
+    options: ScalerOptions,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    statistics: Option<Vec<FeatureStatistics>>,
+
+    #[serde(default, skip_serializing_if = "ScalerMetadata::is_empty")]
+    metadata: ScalerMetadata,
+
+    // Retained by `fit` with `with_retain_samples(true)`, for refits and merges
+    #[serde(rename = "samples_", default, skip_serializing_if = "Option::is_none")]
+    samples: Option<Vec<Vec<f64>>>,
+}
+
+/// Rust-side settings: transform-time options and fit-time quantile ranges.
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ScalerOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    clip: Option<(f64, f64)>,
+
+    #[serde(skip_serializing_if = "is_default")]
+    missing_policy: MissingPolicy,
+
+    #[serde(skip_serializing_if = "is_default_quantile_range")]
+    quantile_range: (f64, f64),
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    feature_quantile_ranges: Vec<QuantileRangeOverride>,
+
+    #[serde(skip_serializing_if = "is_true")]
+    with_centering: bool,
+
+    #[serde(skip_serializing_if = "is_true")]
+    with_scaling: bool,
+
+    #[serde(skip_serializing_if = "is_default")]
+    unit_variance: bool,
+
+    #[serde(skip_serializing_if = "is_default")]
+    scale_estimator: ScaleEstimator,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subsample: Option<(usize, u64)>,
+
+    #[serde(skip_serializing_if = "is_default")]
+    retain_samples: bool,
+
+    #[serde(skip_serializing_if = "is_true")]
+    save_statistics: bool,
+}
+
+impl Default for ScalerOptions {
+    fn default() -> Self {
+        Self {
+            clip: None,
+            missing_policy: MissingPolicy::default(),
+            quantile_range: DEFAULT_QUANTILE_RANGE,
+            feature_quantile_ranges: Vec::new(),
+            with_centering: true,
+            with_scaling: true,
+            unit_variance: false,
+            scale_estimator: ScaleEstimator::default(),
+            subsample: None,
+            retain_samples: false,
+            save_statistics: true,
+        }
+    }
+}
+
+// Version 1: parameters exported from scikit-learn (`get_params()` included, if
+// present), optionally with flat Rust-side settings
+#[derive(Deserialize)]
+struct ScalerFileV1 {
+    // `None` (or missing) when fitted with `with_centering=False`
+    #[serde(rename = "center_", default)]
+    center: Option<Vec<f64>>,
+
+    // `None` (or missing) when fitted with `with_scaling=False`
+    #[serde(rename = "scale_", default)]
+    scale: Option<Vec<f64>>,
+
+    #[serde(rename = "n_features_in_")]
+    n_features_in: usize,
+
+    #[serde(default)]
+    statistics: Option<Vec<FeatureStatistics>>,
+
+    #[serde(default)]
+    clip: Option<(f64, f64)>,
+
+    #[serde(default)]
+    missing_policy: MissingPolicy,
+
+    #[serde(rename = "feature_names_in_", default)]
+    feature_names: Option<Vec<String>>,
+
+    #[serde(default = "default_quantile_range")]
+    quantile_range: (f64, f64),
+
+    #[serde(default = "default_true")]
+    with_centering: bool,
+
+    #[serde(default = "default_true")]
+    with_scaling: bool,
+
+    #[serde(default)]
+    unit_variance: bool,
+}
+
+impl TryFrom<ScalerFileV1> for ScalerFile {
+    type Error = ScalerError;
+
+    /// Fills in the parameters scikit-learn leaves out when centering or
+    /// scaling is disabled.
+    fn try_from(v1: ScalerFileV1) -> Result<Self, ScalerError> {
+        let n_features = v1.n_features_in;
+        let center = match (v1.center, v1.with_centering) {
+            (Some(center), _) => center,
+            (None, false) => vec![0.0; n_features],
+            (None, true) => return Err(invalid_file("Missing 'center_'")),
+        };
+        let scale = match (v1.scale, v1.with_scaling) {
+            (Some(scale), _) => scale,
+            (None, false) => vec![1.0; n_features],
+            (None, true) => return Err(invalid_file("Missing 'scale_'")),
+        };
+
+        Ok(Self {
+            format_version: FORMAT_VERSION,
+            center,
+            scale,
+            n_features_in: n_features,
+            feature_names: v1.feature_names,
+            options: ScalerOptions {
+                clip: v1.clip,
+                missing_policy: v1.missing_policy,
+                quantile_range: v1.quantile_range,
+                with_centering: v1.with_centering,
+                with_scaling: v1.with_scaling,
+                unit_variance: v1.unit_variance,
+                ..ScalerOptions::default()
+            },
+            statistics: v1.statistics,
+            metadata: ScalerMetadata::default(),
+            samples: None,
+        })
+    }
+}
+
+impl ScalerFile {
+    /// Captures the parameters and settings of a scaler.
+    pub(crate) fn from_scaler(scaler: &RobustScaler) -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            center: scaler.center_.clone(),
+            scale: scaler.scale_.clone(),
+            n_features_in: scaler.n_features(),
+            feature_names: scaler.feature_names_.clone(),
+            options: ScalerOptions {
+                clip: scaler.clip_,
+                missing_policy: scaler.missing_,
+                quantile_range: scaler.quantile_range_,
+                feature_quantile_ranges: scaler.quantile_ranges_.clone(),
+                with_centering: scaler.with_centering_,
+                with_scaling: scaler.with_scaling_,
+                unit_variance: scaler.unit_variance_,
+                scale_estimator: scaler.estimator_,
+                subsample: scaler.subsample_,
+                retain_samples: scaler.retain_samples_,
+                save_statistics: scaler.save_statistics_,
+            },
+            statistics: scaler
+                .statistics_
+                .clone()
+                .filter(|_| scaler.save_statistics_),
+            metadata: scaler.metadata_.clone(),
+            samples: scaler.samples_.clone(),
+        }
+    }
+
+    /// Parses a file of any supported version, migrating it to the current one.
+    #[cfg(feature = "json")]
+    pub(crate) fn from_value(value: Value) -> Result<Self, ScalerError> {
+        let version = match value.get("format_version") {
+            None => 1,
+            Some(version) => version
+                .as_u64()
+                .ok_or_else(|| invalid_file("'format_version' must be a positive integer"))?,
+        };
+
+        match version {
+            1 => serde_json::from_value::<ScalerFileV1>(value)
+                .map_err(invalid_file)?
+                .try_into(),
+            2 | 3 => {
+                let mut file: Self = serde_json::from_value(value).map_err(invalid_file)?;
+                file.format_version = FORMAT_VERSION;
+                Ok(file)
+            }
+            found if found > u64::from(FORMAT_VERSION) => {
+                Err(ScalerError::UnsupportedFormatVersion { found })
+            }
+            _ => Err(invalid_file("'format_version' must be a positive integer")),
+        }
+    }
+
+    /// Validates the file and builds the scaler it describes.
+    pub(crate) fn into_scaler(self) -> Result<RobustScaler, ScalerError> {
+        if self.center.len() != self.n_features_in {
+            return Err(invalid_file(
+                "Length of 'center_' does not match 'n_features_in_'",
+            ));
+        }
+        if self.scale.len() != self.n_features_in {
+            return Err(invalid_file(
+                "Length of 'scale_' does not match 'n_features_in_'",
+            ));
+        }
+        if let Some(statistics) = &self.statistics {
+            if statistics.len() != self.n_features_in {
+                return Err(invalid_file(
+                    "Length of 'statistics' does not match 'n_features_in_'",
+                ));
+            }
+        }
+        if let Some(samples) = &self.samples {
+            let is_sorted = |values: &Vec<f64>| {
+                !values.is_empty()
+                    && values.iter().all(|x| x.is_finite())
+                    && values.windows(2).all(|w| w[0] <= w[1])
+            };
+            if samples.len() != self.n_features_in || !samples.iter().all(is_sorted) {
+                return Err(invalid_file(
+                    "'samples_' must hold the sorted, finite values of each feature",
+                ));
+            }
+        }
+        if matches!(self.options.subsample, Some((0, _))) {
+            return Err(invalid_file("'subsample' must keep at least one sample"));
+        }
+        if let Some((min, max)) = self.options.clip {
+            if min > max {
+                return Err(invalid_file("Invalid 'clip' range"));
+            }
+        }
+        let overrides = self.options.feature_quantile_ranges.iter();
+        let mut ranges = overrides.map(|o| o.quantile_range);
+        if !is_valid_quantile_range(self.options.quantile_range)
+            || !ranges.all(is_valid_quantile_range)
+        {
+            return Err(invalid_file("Invalid 'quantile_range'"));
+        }
+        if !self.options.with_centering && self.center.iter().any(|&c| c != 0.0) {
+            return Err(invalid_file(
+                "'center_' must be null or zero when 'with_centering' is false",
+            ));
+        }
+        if !self.options.with_scaling && self.scale.iter().any(|&s| s != 1.0) {
+            return Err(invalid_file(
+                "'scale_' must be null or one when 'with_scaling' is false",
+            ));
+        }
+        if let Some(names) = &self.feature_names {
+            if names.len() != self.n_features_in {
+                return Err(invalid_file(
+                    "Length of 'feature_names_in_' does not match 'n_features_in_'",
+                ));
+            }
+        }
+
+        let mut scaler = RobustScaler::from_params(self.center, self.scale)?;
+        scaler.statistics_ = self.statistics;
+        scaler.clip_ = self.options.clip;
+        scaler.missing_ = self.options.missing_policy;
+        scaler.feature_names_ = self.feature_names;
+        scaler.quantile_range_ = self.options.quantile_range;
+        scaler.quantile_ranges_ = self.options.feature_quantile_ranges;
+        scaler.with_centering_ = self.options.with_centering;
+        scaler.with_scaling_ = self.options.with_scaling;
+        scaler.unit_variance_ = self.options.unit_variance;
+        scaler.estimator_ = self.options.scale_estimator;
+        scaler.subsample_ = self.options.subsample;
+        scaler.retain_samples_ = self.options.retain_samples;
+        scaler.save_statistics_ = self.options.save_statistics;
+        scaler.samples_ = self.samples;
+        scaler.metadata_ = self.metadata;
+        Ok(scaler)
+    }
+}
+
+/// Loads a scaler from a document in another self-describing format
+/// (MessagePack, YAML, TOML), decoded into a JSON value by the format's serde
+/// implementation. Going through a JSON value gives every format the version
+/// migration and validation of `from_json`.
+#[cfg(any(feature = "msgpack", feature = "yaml", feature = "toml"))]
+pub(crate) fn scaler_from_document<E: ToString>(
+    document: Result<Value, E>,
+) -> Result<RobustScaler, ScalerError> {
+    ScalerFile::from_value(document.map_err(invalid_file)?)?.into_scaler()
+}
+
+fn invalid_file<M: ToString>(message: M) -> ScalerError {
+    ScalerError::InvalidFile {
+        message: message.to_string(),
+    }
+}
+
+fn default_quantile_range() -> (f64, f64) {
+    DEFAULT_QUANTILE_RANGE
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+fn is_default_quantile_range(range: &(f64, f64)) -> bool {
+    *range == DEFAULT_QUANTILE_RANGE
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_load_all_versions() {
+        let v1 = json!({
+            "center_": [1.0, 2.0],
+            "scale_": [3.0, 4.0],
+            "n_features_in_": 2,
+            "clip": [-5.0, 5.0]
+        });
+        let scaler = ScalerFile::from_value(v1).unwrap().into_scaler().unwrap();
+        assert_eq!(scaler.scale(), &[3.0, 4.0]);
+        assert_eq!(scaler.clip(), Some((-5.0, 5.0)));
+
+        let v2 = serde_json::to_value(ScalerFile::from_scaler(&scaler)).unwrap();
+        assert_eq!(v2["format_version"], json!(FORMAT_VERSION));
+        assert_eq!(v2["options"]["clip"], json!([-5.0, 5.0]));
+        let reloaded = ScalerFile::from_value(v2).unwrap().into_scaler().unwrap();
+        assert_eq!(reloaded.clip(), scaler.clip());
+
+        let future = json!({
+            "format_version": 99,
+            "center_": [],
+            "scale_": [],
+            "n_features_in_": 0
+        });
+        let err = ScalerFile::from_value(future).err().unwrap();
+        assert!(err.to_string().contains("format_version 99"));
+
+        let v2 = json!({
+            "format_version": 2,
+            "center_": [1.0],
+            "scale_": [3.0],
+            "n_features_in_": 1,
+            "options": {"quantile_range": [10.0, 90.0]}
+        });
+        let scaler = ScalerFile::from_value(v2).unwrap().into_scaler().unwrap();
+        assert_eq!(scaler.quantile_range(), (10.0, 90.0));
+
+        let unknown = json!({
+            "format_version": FORMAT_VERSION,
+            "center_": [1.0],
+            "scale_": [3.0],
+            "n_features_in_": 1,
+            "options": {"not_yet_invented": true}
+        });
+        let err = ScalerFile::from_value(unknown).err().unwrap();
+        assert!(err.to_string().contains("not_yet_invented"));
+    }
+
+    #[test]
+    fn test_load_sklearn_params() {
+        let sklearn = json!({
+            "center_": null,
+            "scale_": [2.0, 4.0],
+            "n_features_in_": 2,
+            "feature_names_in_": ["a", "b"],
+            "quantile_range": [10.0, 90.0],
+            "with_centering": false,
+            "with_scaling": true,
+            "unit_variance": true
+        });
+        let scaler = ScalerFile::from_value(sklearn)
+            .unwrap()
+            .into_scaler()
+            .unwrap();
+        assert_eq!(scaler.center(), &[0.0, 0.0]);
+        assert_eq!(scaler.quantile_range(), (10.0, 90.0));
+        assert!(!scaler.centering() && scaler.scaling() && scaler.unit_variance());
+
+        let v2 = serde_json::to_value(ScalerFile::from_scaler(&scaler)).unwrap();
+        assert_eq!(v2["options"]["with_centering"], json!(false));
+        assert!(v2["options"].get("with_scaling").is_none());
+        let reloaded = ScalerFile::from_value(v2).unwrap().into_scaler().unwrap();
+        assert!(!reloaded.centering() && reloaded.unit_variance());
+
+        let inconsistent = json!({
+            "center_": [1.0],
+            "scale_": [1.0],
+            "n_features_in_": 1,
+            "with_centering": false
+        });
+        let err = ScalerFile::from_value(inconsistent).unwrap().into_scaler();
+        assert!(matches!(err, Err(ScalerError::InvalidFile { .. })));
+        let missing = json!({"scale_": [1.0], "n_features_in_": 1});
+        assert!(ScalerFile::from_value(missing).is_err());
+        let empty_samples = json!({
+            "format_version": FORMAT_VERSION,
+            "center_": [1.0],
+            "scale_": [1.0],
+            "n_features_in_": 1,
+            "samples_": [[]]
+        });
+        let err = ScalerFile::from_value(empty_samples).unwrap().into_scaler();
+        assert!(matches!(err, Err(ScalerError::InvalidFile { .. })));
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::sync::Arc;
+
+use ndarray::Array2;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::Transformer;
+
+/// An element-wise function applied by a `FunctionTransformer`, saved by name.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Func {
+    Identity,
+    #[cfg(feature = "std")]
+    Log1p,
+    #[cfg(feature = "std")]
+    Expm1,
+    #[cfg(feature = "std")]
+    Sqrt,
+    #[cfg(feature = "std")]
+    Square,
+    #[serde(serialize_with = "custom_func", skip_deserializing)]
+    Custom(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+}
+
+/// Fails to save a closure, which has no name to be loaded back by.
+fn custom_func<S: Serializer>(
+    _func: &Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+    _serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Err(serde::ser::Error::custom(
+        "A FunctionTransformer built from closures cannot be saved",
+    ))
+}
+
+impl Func {
+    fn apply(&self, x: f64) -> f64 {
+        match self {
+            Func::Identity => x,
+            #[cfg(feature = "std")]
+            Func::Log1p => x.ln_1p(),
+            #[cfg(feature = "std")]
+            Func::Expm1 => x.exp_m1(),
+            #[cfg(feature = "std")]
+            Func::Sqrt => x.sqrt(),
+            #[cfg(feature = "std")]
+            Func::Square => x * x,
+            Func::Custom(f) => f(x),
+        }
+    }
+}
+
+/// FunctionTransformer: applies a forward element-wise function on `transform`
+/// and its inverse on `inverse_transform`.
+///
+/// Stateless: `fit` does nothing, so it can be placed anywhere in a chain of
+/// `Transformer`s, e.g. a `log1p` on monetary columns before robust scaling.
+///
+/// The built-in `log1p`, `expm1`, `sqrt` and `square` need the `std` feature
+/// (for the float functions); custom closures work everywhere. Only the
+/// built-in functions can be saved, e.g. in a `Pipeline`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FunctionTransformer {
+    func: Func,
+    inverse_func: Func,
+}
+
+impl FunctionTransformer {
+    /// Creates a transformer from custom forward and inverse closures.
+    pub fn new<F, G>(func: F, inverse_func: G) -> Self
+    where
+        F: Fn(f64) -> f64 + Send + Sync + 'static,
+        G: Fn(f64) -> f64 + Send + Sync + 'static,
+    {
+        Self {
+            func: Func::Custom(Arc::new(func)),
+            inverse_func: Func::Custom(Arc::new(inverse_func)),
+        }
+    }
+
+    /// Creates a transformer from a custom forward closure; the inverse is the identity.
+    pub fn from_fn<F>(func: F) -> Self
+    where
+        F: Fn(f64) -> f64 + Send + Sync + 'static,
+    {
+        Self {
+            func: Func::Custom(Arc::new(func)),
+            inverse_func: Func::Identity,
+        }
+    }
+
+    /// Identity in both directions.
+    pub fn identity() -> Self {
+        Self {
+            func: Func::Identity,
+            inverse_func: Func::Identity,
+        }
+    }
+
+    /// `ln(1 + x)` forward, `exp(x) - 1` inverse.
+    #[cfg(feature = "std")]
+    pub fn log1p() -> Self {
+        Self {
+            func: Func::Log1p,
+            inverse_func: Func::Expm1,
+        }
+    }
+
+    /// `exp(x) - 1` forward, `ln(1 + x)` inverse.
+    #[cfg(feature = "std")]
+    pub fn expm1() -> Self {
+        Self {
+            func: Func::Expm1,
+            inverse_func: Func::Log1p,
+        }
+    }
+
+    /// `sqrt(x)` forward, `x²` inverse.
+    #[cfg(feature = "std")]
+    pub fn sqrt() -> Self {
+        Self {
+            func: Func::Sqrt,
+            inverse_func: Func::Square,
+        }
+    }
+
+    /// `x²` forward, `sqrt(x)` inverse.
+    #[cfg(feature = "std")]
+    pub fn square() -> Self {
+        Self {
+            func: Func::Square,
+            inverse_func: Func::Sqrt,
+        }
+    }
+
+    /// Applies the forward function to every value.
+    pub fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        data.mapv(|x| self.func.apply(x))
+    }
+
+    /// Applies the inverse function to every value.
+    pub fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        data.mapv(|x| self.inverse_func.apply(x))
+    }
+}
+
+impl Transformer for FunctionTransformer {
+    fn fit(&mut self, _data: &Array2<f64>) {}
+
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        FunctionTransformer::transform(self, data)
+    }
+
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        FunctionTransformer::inverse_transform(self, data)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_function_transformer() {
+        let data = arr2(&[[0.0, 4.0], [1.0, 9.0]]);
+
+        let log1p = FunctionTransformer::log1p();
+        let transformed = log1p.transform(&data);
+        assert!((transformed[[1, 0]] - 2f64.ln()).abs() < 1e-12);
+        let restored = log1p.inverse_transform(&transformed);
+        assert!(restored
+            .iter()
+            .zip(&data)
+            .all(|(a, b)| (a - b).abs() < 1e-9));
+
+        assert_eq!(FunctionTransformer::sqrt().transform(&data)[[1, 1]], 3.0);
+
+        let mut custom = FunctionTransformer::new(|x| x + 1.0, |x| x - 1.0);
+        let shifted = Transformer::fit_transform(&mut custom, &data);
+        assert_eq!(shifted, arr2(&[[1.0, 5.0], [2.0, 10.0]]));
+        assert_eq!(custom.inverse_transform(&shifted), data);
+    }
+}
@@ -0,0 +1,281 @@
+use std::borrow::Cow;
+
+use ndarray::Array2;
+use wgpu::util::DeviceExt;
+
+use crate::RobustScaler;
+
+/// Invocations per workgroup of the scaling kernel.
+const WORKGROUP_SIZE: u32 = 256;
+
+/// Maximum number of workgroups per dispatch dimension guaranteed by WebGPU.
+const MAX_WORKGROUPS_PER_DIMENSION: u32 = 65535;
+
+/// Affine per-column kernel: `data[i] = (data[i] - center[j]) / scale[j]`,
+/// where `j` is the column of the `i`-th value of a row-major matrix.
+const SHADER: &str = r#"
+struct Params {
+    n_features: u32,
+    n_values: u32,
+    use_clip: u32,
+    clip_min: f32,
+    clip_max: f32,
+}
+
+@group(0) @binding(0) var<storage, read_write> data: array<f32>;
+@group(0) @binding(1) var<storage, read> center: array<f32>;
+@group(0) @binding(2) var<storage, read> scale: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+@compute @workgroup_size(256)
+fn main(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let i = id.y * groups.x * 256u + id.x;
+    if (i >= params.n_values) {
+        return;
+    }
+    let j = i % params.n_features;
+    var x = (data[i] - center[j]) / scale[j];
+    if (params.use_clip != 0u) {
+        x = clamp(x, params.clip_min, params.clip_max);
+    }
+    data[i] = x;
+}
+"#;
+
+/// A fitted `RobustScaler` uploaded to a GPU device (enabled by the `gpu` feature).
+///
+/// Scales row-major `f32` matrices that live in device buffers, so inference
+/// pipelines running on the GPU do not need a round-trip to the host. WGSL has
+/// no portable `f64`, so parameters are narrowed to `f32`. The clip range is
+/// honoured; the missing-value policy is not (NaN follows the device's float
+/// semantics).
+pub struct GpuScaler {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    center: wgpu::Buffer,
+    scale: wgpu::Buffer,
+    n_features: u32,
+    clip: Option<(f32, f32)>,
+}
+
+impl GpuScaler {
+    /// Uploads `scaler` to the default GPU adapter.
+    ///
+    /// # Returns
+    /// `Ok(GpuScaler)`, or `Err` if no adapter or device is available.
+    pub fn new(scaler: &RobustScaler) -> std::io::Result<Self> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .map_err(std::io::Error::other)?;
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))
+                .map_err(std::io::Error::other)?;
+
+        Ok(Self::with_device(scaler, &device, &queue))
+    }
+
+    /// Uploads `scaler` to an existing device, e.g. the one of an inference pipeline.
+    pub fn with_device(scaler: &RobustScaler, device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        assert!(scaler.n_features() > 0, "Cannot upload an unfitted scaler");
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("robust_scaler"),
+            source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(SHADER)),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("robust_scaler"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let upload = |label, values: &[f64]| {
+            let values: Vec<f32> = values.iter().map(|&v| v as f32).collect();
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(label),
+                contents: &to_bytes(&values),
+                usage: wgpu::BufferUsages::STORAGE,
+            })
+        };
+
+        Self {
+            device: device.clone(),
+            queue: queue.clone(),
+            pipeline,
+            center: upload("robust_scaler center", scaler.center()),
+            scale: upload("robust_scaler scale", scaler.scale()),
+            n_features: scaler.n_features() as u32,
+            clip: scaler.clip().map(|(min, max)| (min as f32, max as f32)),
+        }
+    }
+
+    /// Records the scaling of a device buffer into `encoder`, without submitting it.
+    ///
+    /// # Arguments
+    /// * `encoder` - Command encoder of the caller's pipeline.
+    /// * `data` - Row-major `f32` matrix with `STORAGE` usage, scaled in place.
+    /// * `n_rows` - Number of rows in `data`.
+    pub fn encode_transform(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        data: &wgpu::Buffer,
+        n_rows: u32,
+    ) {
+        let n_values = n_rows
+            .checked_mul(self.n_features)
+            .expect("Too many values for a single dispatch");
+        assert!(
+            data.size() >= u64::from(n_values) * 4,
+            "Buffer is smaller than {} rows",
+            n_rows
+        );
+
+        let (clip_min, clip_max) = self.clip.unwrap_or((0.0, 0.0));
+        let mut params = Vec::with_capacity(32);
+        for word in [self.n_features, n_values, self.clip.is_some() as u32] {
+            params.extend_from_slice(&word.to_ne_bytes());
+        }
+        params.extend_from_slice(&to_bytes(&[clip_min, clip_max]));
+        params.resize(32, 0); // Uniform structs are padded to 16 bytes
+        let params = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("robust_scaler params"),
+                contents: &params,
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("robust_scaler"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: data.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: self.center.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.scale.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: params.as_entire_binding(),
+                },
+            ],
+        });
+
+        let groups = n_values.div_ceil(WORKGROUP_SIZE);
+        let groups_x = groups.min(MAX_WORKGROUPS_PER_DIMENSION);
+        let groups_y = groups.div_ceil(groups_x.max(1));
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("robust_scaler"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(groups_x, groups_y, 1);
+    }
+
+    /// Scales a device buffer in place and submits the work to the queue.
+    ///
+    /// See [`GpuScaler::encode_transform`] for the buffer layout.
+    pub fn transform_buffer(&self, data: &wgpu::Buffer, n_rows: u32) -> wgpu::SubmissionIndex {
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("robust_scaler"),
+            });
+        self.encode_transform(&mut encoder, data, n_rows);
+        self.queue.submit([encoder.finish()])
+    }
+
+    /// Uploads a host matrix, scales it on the device and reads it back.
+    ///
+    /// Mostly useful for testing; keep data on the device with
+    /// [`GpuScaler::transform_buffer`] in production.
+    pub fn transform(&self, data: &Array2<f32>) -> Array2<f32> {
+        assert_eq!(data.ncols(), self.n_features as usize);
+
+        let values: Vec<f32> = data.iter().copied().collect(); // Row-major order
+        let bytes = to_bytes(&values);
+        let buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("robust_scaler data"),
+                contents: &bytes,
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            });
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("robust_scaler staging"),
+            size: bytes.len() as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("robust_scaler"),
+            });
+        self.encode_transform(&mut encoder, &buffer, data.nrows() as u32);
+        encoder.copy_buffer_to_buffer(&buffer, 0, &staging, 0, bytes.len() as u64);
+        self.queue.submit([encoder.finish()]);
+
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| {
+            result.expect("Failed to map GPU buffer")
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .expect("Failed to wait for the GPU");
+
+        let view = slice.get_mapped_range().expect("Failed to read GPU buffer");
+        let scaled = view
+            .chunks_exact(4)
+            .map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        Array2::from_shape_vec(data.raw_dim(), scaled).unwrap()
+    }
+}
+
+impl RobustScaler {
+    /// Uploads the fitted parameters to a GPU device (see [`GpuScaler`]).
+    pub fn to_gpu(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> GpuScaler {
+        GpuScaler::with_device(self, device, queue)
+    }
+}
+
+fn to_bytes(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_ne_bytes()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    /// Run with `cargo test --features gpu -- --ignored` on a machine with a GPU.
+    #[test]
+    #[ignore = "needs a GPU adapter"]
+    fn test_gpu_transform() {
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0])
+            .unwrap()
+            .with_clip(-1.0, 1.0);
+        let gpu = GpuScaler::new(&scaler).expect("No GPU adapter available");
+
+        let data = arr2(&[[3.0_f32, 6.0], [1.0, 100.0]]);
+        assert_eq!(gpu.transform(&data), arr2(&[[1.0, 1.0], [0.0, 1.0]]));
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use ndarray::{Array2, Axis};
+
+use crate::{RobustScaler, ScalerError};
+
+/// What `GroupedRobustScaler::transform` does with rows whose group was not seen during `fit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupFallback {
+    /// Scale with the parameters fitted on all rows, regardless of group.
+    #[default]
+    Global,
+    /// Leave the row unchanged.
+    Passthrough,
+    /// Fail with `ScalerError::UnknownGroup`.
+    Error,
+}
+
+/// GroupedRobustScaler: fits one `RobustScaler` per group (e.g. per store or per sensor).
+///
+/// Every row is tagged with a group key; `transform` scales each row with the
+/// median/IQR of its own group and uses the configured `GroupFallback` for
+/// groups that were not seen during `fit`.
+pub struct GroupedRobustScaler<K> {
+    scalers: BTreeMap<K, RobustScaler>, // One scaler per group
+    global: RobustScaler,               // Scaler fitted on all rows, and template of the others
+    fallback: GroupFallback,
+}
+
+impl<K: Ord + Clone> Default for GroupedRobustScaler<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone> GroupedRobustScaler<K> {
+    /// Creates a new empty GroupedRobustScaler with the `Global` fallback.
+    pub fn new() -> Self {
+        Self {
+            scalers: BTreeMap::new(),
+            global: RobustScaler::new(),
+            fallback: GroupFallback::default(),
+        }
+    }
+
+    /// Sets the scaler whose settings (quantile range, centering, estimator,
+    /// ...) every group and the global scaler are fitted with.
+    pub fn with_template(mut self, template: RobustScaler) -> Self {
+        self.global = template.unfitted();
+        self
+    }
+
+    /// Sets what to do with groups unseen during `fit`.
+    pub fn with_fallback(mut self, fallback: GroupFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Fits one scaler per group, plus a global scaler over all rows.
+    ///
+    /// # Arguments
+    /// * `data` - A 2D array where rows are samples and columns are features.
+    /// * `groups` - Group key of each row (length must match the number of rows).
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, data: &Array2<f64>, groups: &[K]) -> &Self {
+        assert_eq!(data.nrows(), groups.len());
+
+        let mut rows: BTreeMap<&K, Vec<usize>> = BTreeMap::new();
+        for (i, group) in groups.iter().enumerate() {
+            rows.entry(group).or_default().push(i);
+        }
+
+        self.scalers.clear();
+        for (group, indices) in rows {
+            let mut scaler = self.global.unfitted();
+            scaler.fit(&data.select(Axis(0), &indices));
+            self.scalers.insert(group.clone(), scaler);
+        }
+        self.global.fit(data);
+
+        self
+    }
+
+    /// Scales every row with the parameters of its group.
+    ///
+    /// # Panics
+    /// If `try_transform` fails.
+    pub fn transform(&self, data: &Array2<f64>, groups: &[K]) -> Array2<f64> {
+        self.try_transform(data, groups)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Scales every row with the parameters of its group, returning an error
+    /// instead of panicking.
+    ///
+    /// # Returns
+    /// The scaled data, `ScalerError::FeatureCountMismatch` if the data does not
+    /// have as many features as the scalers, or `ScalerError::UnknownGroup` if a
+    /// group was not seen during `fit` and the fallback is `GroupFallback::Error`.
+    ///
+    /// # Panics
+    /// If `groups` does not have one key per row.
+    pub fn try_transform(
+        &self,
+        data: &Array2<f64>,
+        groups: &[K],
+    ) -> Result<Array2<f64>, ScalerError> {
+        self.apply(data, groups, |scaler, x, center, scale| {
+            scaler.scale_value(x, center, scale)
+        })
+    }
+
+    /// Reverts the scaling of every row with the parameters of its group.
+    ///
+    /// # Panics
+    /// If `try_inverse_transform` fails.
+    pub fn inverse_transform(&self, data: &Array2<f64>, groups: &[K]) -> Array2<f64> {
+        self.try_inverse_transform(data, groups)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reverts the scaling of every row with the parameters of its group,
+    /// returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// The unscaled data, or the errors of `try_transform`.
+    ///
+    /// # Panics
+    /// If `groups` does not have one key per row.
+    pub fn try_inverse_transform(
+        &self,
+        data: &Array2<f64>,
+        groups: &[K],
+    ) -> Result<Array2<f64>, ScalerError> {
+        self.apply(data, groups, |_, x, center, scale| x * scale + center)
+    }
+
+    /// Fits the scalers and transforms the data in one step.
+    pub fn fit_transform(&mut self, data: &Array2<f64>, groups: &[K]) -> Array2<f64> {
+        self.fit(data, groups);
+        self.transform(data, groups)
+    }
+
+    /// Returns the scaler fitted for `group`, if it was seen during `fit`.
+    pub fn scaler(&self, group: &K) -> Option<&RobustScaler> {
+        self.scalers.get(group)
+    }
+
+    /// Returns the scaler fitted on all rows.
+    pub fn global_scaler(&self) -> &RobustScaler {
+        &self.global
+    }
+
+    /// Returns the groups seen during `fit`, in sorted order.
+    pub fn groups(&self) -> impl Iterator<Item = &K> {
+        self.scalers.keys()
+    }
+
+    fn apply<F>(&self, data: &Array2<f64>, groups: &[K], f: F) -> Result<Array2<f64>, ScalerError>
+    where
+        F: Fn(&RobustScaler, f64, f64, f64) -> f64,
+    {
+        assert_eq!(data.nrows(), groups.len());
+        if data.ncols() != self.global.n_features() {
+            return Err(ScalerError::FeatureCountMismatch {
+                expected: self.global.n_features(),
+                found: data.ncols(),
+            });
+        }
+
+        let mut result = data.clone();
+        for (i, (mut row, group)) in result.rows_mut().into_iter().zip(groups).enumerate() {
+            let scaler = match (self.scalers.get(group), self.fallback) {
+                (Some(scaler), _) => scaler,
+                (None, GroupFallback::Global) => &self.global,
+                (None, GroupFallback::Passthrough) => continue,
+                (None, GroupFallback::Error) => return Err(ScalerError::UnknownGroup { row: i }),
+            };
+            for ((x, &center), &scale) in row.iter_mut().zip(scaler.center()).zip(scaler.scale()) {
+                *x = f(scaler, *x, center, scale);
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_grouped_scaler() {
+        let data = arr2(&[[1.0], [3.0], [5.0], [100.0], [300.0], [500.0]]);
+        let groups = ["a", "a", "a", "b", "b", "b"];
+
+        let mut scaler = GroupedRobustScaler::new().with_fallback(GroupFallback::Passthrough);
+        let scaled = scaler.fit_transform(&data, &groups);
+        assert_eq!(
+            scaled.column(0).to_vec(),
+            vec![-1.0, 0.0, 1.0, -1.0, 0.0, 1.0]
+        );
+        assert_eq!(scaler.groups().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let unseen = scaler.transform(&arr2(&[[7.0]]), &["c"]);
+        assert_eq!(unseen, arr2(&[[7.0]]));
+        assert_eq!(scaler.inverse_transform(&scaled, &groups), data);
+
+        // Groups use the template's settings: here the full range, 5 - 1
+        let mut strict = GroupedRobustScaler::new()
+            .with_fallback(GroupFallback::Error)
+            .with_template(RobustScaler::new().with_quantile_range(0.0, 100.0));
+        strict.fit(&data, &groups);
+        assert_eq!(strict.scaler(&"a").unwrap().scale(), &[4.0]);
+        assert_eq!(
+            strict.try_transform(&arr2(&[[1.0], [7.0]]), &["a", "c"]),
+            Err(ScalerError::UnknownGroup { row: 1 })
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use ndarray::{ArrayBase, Axis, Data, Ix2};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{RobustScaler, ScalerError};
+
+/// Iterator adapter returned by [`RobustScaler::transform_iter`].
+///
+/// Scales one row at a time as it is pulled, so memory use does not depend on
+/// the number of rows.
+pub struct TransformIter<'a, I> {
+    scaler: &'a RobustScaler,
+    rows: I,
+    row: usize, // Index of the next row, for error context
+}
+
+impl<I, R> Iterator for TransformIter<'_, I>
+where
+    I: Iterator<Item = R>,
+    R: AsRef<[f64]>,
+{
+    type Item = Result<Vec<f64>, ScalerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.rows.next()?;
+        let scaled = self.scaler.try_transform_row(self.row, values.as_ref());
+        self.row += 1;
+        Some(scaled)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl RobustScaler {
+    /// Lazily scales rows pulled from any iterator (e.g. a CSV reader).
+    ///
+    /// # Arguments
+    /// * `rows` - Rows of feature values, each as long as `n_features`.
+    ///
+    /// # Returns
+    /// An iterator yielding one `Result` per row; errors carry the row index.
+    pub fn transform_iter<I, R>(&self, rows: I) -> TransformIter<'_, I::IntoIter>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[f64]>,
+    {
+        TransformIter {
+            scaler: self,
+            rows: rows.into_iter(),
+            row: 0,
+        }
+    }
+
+    /// Scales the rows of 2D data on the rayon pool, yielding each scaled row
+    /// as a `Vec<f64>` (enabled by the `parallel` feature).
+    ///
+    /// Lets per-row work (feature hashing, model scoring, ...) run in the same
+    /// parallel pass as the scaling, without materializing the scaled matrix:
+    ///
+    /// ```ignore
+    /// let scores: Vec<f64> = scaler
+    ///     .par_transform_iter(&data)
+    ///     .map(|row| model.score(&row.unwrap()))
+    ///     .collect();
+    /// ```
+    ///
+    /// # Returns
+    /// An indexed parallel iterator yielding one `Result` per row, in row order
+    /// when collected; errors carry the row index.
+    #[cfg(feature = "parallel")]
+    pub fn par_transform_iter<'a, S: Data<Elem = f64>>(
+        &'a self,
+        data: &'a ArrayBase<S, Ix2>,
+    ) -> impl IndexedParallelIterator<Item = Result<Vec<f64>, ScalerError>> + 'a {
+        data.axis_iter(Axis(0))
+            .into_par_iter()
+            .enumerate()
+            .map(move |(i, row)| match row.as_slice() {
+                Some(values) => self.try_transform_row(i, values),
+                None => self.try_transform_row(i, &row.to_vec()),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_iter() {
+        let scaler = RobustScaler::from_params(vec![1.0], vec![2.0]).unwrap();
+        let rows = (0..3).map(|i| vec![i as f64]);
+
+        let mut scaled = scaler.transform_iter(rows);
+        assert_eq!(scaled.next(), Some(Ok(vec![-0.5])));
+        assert_eq!(scaled.next(), Some(Ok(vec![0.0])));
+        assert_eq!(scaled.next(), Some(Ok(vec![0.5])));
+        assert_eq!(scaled.next(), None);
+
+        let errors: Vec<_> = scaler
+            .transform_iter([vec![1.0], vec![1.0, 2.0]])
+            .filter_map(Result::err)
+            .collect();
+        assert_eq!(
+            errors,
+            vec![ScalerError::RowLengthMismatch {
+                row: 1,
+                expected: 1,
+                found: 2
+            }]
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_transform_iter() {
+        use ndarray::{arr2, ShapeBuilder};
+
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0]).unwrap();
+        let data = arr2(&[[1.0, 2.0], [3.0, 6.0], [f64::INFINITY, 2.0]]);
+        let mut fortran = ndarray::Array2::zeros((3, 2).f());
+        fortran.assign(&data);
+
+        for input in [&data, &fortran] {
+            let rows: Vec<_> = scaler.par_transform_iter(input).collect();
+            assert_eq!(rows[0], Ok(vec![0.0, 0.0]));
+            assert_eq!(rows[1], Ok(vec![1.0, 1.0]));
+            assert!(matches!(
+                rows[2],
+                Err(ScalerError::NonFiniteValue { row: 2, .. })
+            ));
+        }
+    }
+}
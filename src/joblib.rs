@@ -0,0 +1,87 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::Command;
+
+use crate::RobustScaler;
+
+/// Python helper printing the parameters of a pickled RobustScaler as JSON.
+const JOBLIB_TO_JSON: &str = include_str!("joblib_to_json.py");
+
+impl RobustScaler {
+    /// Loads a RobustScaler fitted in scikit-learn and saved with `joblib.dump` (or `pickle`).
+    ///
+    /// The pickle is read by a small bundled Python script run with `python3`,
+    /// which must be able to import scikit-learn. The file may hold a
+    /// `RobustScaler` or a `Pipeline` containing exactly one.
+    ///
+    /// The fitted parameters are loaded along with the settings of `get_params()`
+    /// (`quantile_range`, `with_centering`, `with_scaling`, `unit_variance`), so
+    /// the scaler can be refitted the same way.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the joblib/pickle file.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)` if successful, `Err` otherwise.
+    pub fn from_joblib<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        Self::from_joblib_with_python(path, "python3")
+    }
+
+    /// Same as [`RobustScaler::from_joblib`], using the given Python interpreter
+    /// (e.g. the one of a virtualenv where the model was trained).
+    pub fn from_joblib_with_python<P: AsRef<Path>, S: AsRef<OsStr>>(
+        path: P,
+        python: S,
+    ) -> std::io::Result<Self> {
+        let output = Command::new(python)
+            .arg("-c")
+            .arg(JOBLIB_TO_JSON)
+            .arg(path.as_ref())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(std::io::Error::other(format!(
+                "Failed to convert '{}': {}",
+                path.as_ref().display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(Self::from_json_slice(&output.stdout)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "needs python3 with joblib"]
+    fn test_from_joblib() {
+        let path = std::env::temp_dir().join("robust_scaler_test_from_joblib.pkl");
+
+        // Any pickled object with the fitted attributes is accepted, which lets
+        // this test run without scikit-learn installed (but not without Python).
+        let script = "import pickle, sys, types\n\
+            obj = types.SimpleNamespace(center_=[1.5, 2.0], scale_=[0.1, 3.0], n_features_in_=2,\n\
+                quantile_range=(10.0, 90.0), with_centering=True, with_scaling=True,\n\
+                unit_variance=True)\n\
+            pickle.dump(obj, open(sys.argv[1], 'wb'))";
+        let status = Command::new("python3")
+            .arg("-c")
+            .arg(script)
+            .arg(&path)
+            .status()
+            .expect("python3 is needed to run this test");
+        assert!(status.success());
+
+        let scaler = RobustScaler::from_joblib(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(scaler.center(), &[1.5, 2.0]);
+        assert_eq!(scaler.scale(), &[0.1, 3.0]);
+        assert_eq!(scaler.quantile_range(), (10.0, 90.0));
+        assert!(scaler.unit_variance());
+
+        assert!(RobustScaler::from_joblib(&path).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+"""Print the parameters of a fitted scikit-learn RobustScaler as JSON.
+
+Usage: python3 joblib_to_json.py PATH
+
+PATH is a joblib or pickle file holding either a RobustScaler or a Pipeline
+containing exactly one RobustScaler. Used by `RobustScaler::from_joblib`.
+"""
+import json
+import pickle
+import sys
+
+
+def load(path):
+    try:
+        import joblib
+    except ImportError:
+        with open(path, "rb") as f:
+            return pickle.load(f)
+    return joblib.load(path)
+
+
+def is_scaler(obj):
+    return hasattr(obj, "center_") and hasattr(obj, "scale_")
+
+
+def find_scaler(obj):
+    if is_scaler(obj):
+        return obj
+    steps = [step for _, step in getattr(obj, "steps", [])]
+    scalers = [step for step in steps if is_scaler(step)]
+    if len(scalers) != 1:
+        sys.exit(
+            "expected a fitted RobustScaler or a Pipeline with exactly one, got %s"
+            % type(obj).__name__
+        )
+    return scalers[0]
+
+
+def to_list(values):
+    return [float(v) for v in values]
+
+
+def main():
+    scaler = find_scaler(load(sys.argv[1]))
+    center = scaler.center_
+    scale = scaler.scale_
+    n_features = getattr(scaler, "n_features_in_", None)
+    if n_features is None:
+        n_features = len(center if center is not None else scale)
+
+    # with_centering=False / with_scaling=False leave the attribute unset.
+    params = {
+        "center_": to_list(center) if center is not None else [0.0] * n_features,
+        "scale_": to_list(scale) if scale is not None else [1.0] * n_features,
+        "n_features_in_": int(n_features),
+    }
+    # The fit-time settings, so the scaler can be refitted the same way.
+    settings = getattr(scaler, "get_params", lambda: vars(scaler))()
+    if "quantile_range" in settings:
+        params["quantile_range"] = to_list(settings["quantile_range"])
+    for key in ("with_centering", "with_scaling", "unit_variance"):
+        if key in settings:
+            params[key] = bool(settings[key])
+
+    names = getattr(scaler, "feature_names_in_", None)
+    if names is not None:
+        params["feature_names_in_"] = [str(name) for name in names]
+
+    json.dump(params, sys.stdout)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,131 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+use crate::ScalerError;
+
+/// LabelEncoder: maps target labels to class indices `0..n_classes` and back.
+///
+/// Classes are sorted, like scikit-learn's `classes_`. Works with any ordered
+/// label type, e.g. `String` or `i64`.
+pub struct LabelEncoder<T> {
+    classes_: Vec<T>,           // Classes, by index
+    index_: BTreeMap<T, usize>, // Index of each class
+}
+
+impl<T: Ord + Clone> Default for LabelEncoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> LabelEncoder<T> {
+    /// Creates a new unfitted LabelEncoder.
+    pub fn new() -> Self {
+        Self {
+            classes_: Vec::new(),
+            index_: BTreeMap::new(),
+        }
+    }
+
+    /// Builds a fitted encoder from known classes (e.g. scikit-learn's `classes_`).
+    ///
+    /// # Panics
+    /// If a class is listed twice.
+    pub fn from_classes(classes: Vec<T>) -> Self {
+        let index: BTreeMap<T, usize> = classes
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, c)| (c, i))
+            .collect();
+        assert_eq!(index.len(), classes.len(), "Duplicate class");
+        Self {
+            classes_: classes,
+            index_: index,
+        }
+    }
+
+    /// Learns the classes of `labels`.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, labels: &[T]) -> &Self {
+        let classes: BTreeSet<&T> = labels.iter().collect();
+        *self = Self::from_classes(classes.into_iter().cloned().collect());
+        self
+    }
+
+    /// Encodes `labels` as class indices.
+    ///
+    /// # Panics
+    /// If a label was not seen during `fit`.
+    pub fn transform(&self, labels: &[T]) -> Vec<usize> {
+        self.try_transform(labels)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Encodes `labels`, returning `ScalerError::UnknownCategory` (with column 0)
+    /// for the first unknown label instead of panicking.
+    pub fn try_transform(&self, labels: &[T]) -> Result<Vec<usize>, ScalerError> {
+        labels
+            .iter()
+            .enumerate()
+            .map(|(row, label)| {
+                self.index_
+                    .get(label)
+                    .copied()
+                    .ok_or(ScalerError::UnknownCategory { row, column: 0 })
+            })
+            .collect()
+    }
+
+    /// Maps class indices back to their labels.
+    ///
+    /// # Panics
+    /// If an index is not below the number of classes.
+    pub fn inverse_transform(&self, indices: &[usize]) -> Vec<T> {
+        indices
+            .iter()
+            .map(|&i| {
+                assert!(i < self.classes_.len(), "Invalid class index {}", i);
+                self.classes_[i].clone()
+            })
+            .collect()
+    }
+
+    /// Learns the classes and encodes the labels in one step.
+    pub fn fit_transform(&mut self, labels: &[T]) -> Vec<usize> {
+        self.fit(labels);
+        self.transform(labels)
+    }
+
+    /// Returns the classes, by index.
+    pub fn classes(&self) -> &[T] {
+        &self.classes_
+    }
+
+    /// Returns the number of classes.
+    pub fn n_classes(&self) -> usize {
+        self.classes_.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_encoder() {
+        let labels = ["spam", "ham", "spam", "eggs"];
+        let mut encoder = LabelEncoder::new();
+        let encoded = encoder.fit_transform(&labels);
+
+        assert_eq!(encoder.classes(), &["eggs", "ham", "spam"]);
+        assert_eq!(encoded, vec![2, 1, 2, 0]);
+        assert_eq!(encoder.inverse_transform(&encoded), labels);
+        assert_eq!(
+            encoder.try_transform(&["ham", "bacon"]),
+            Err(ScalerError::UnknownCategory { row: 1, column: 0 })
+        );
+    }
+}
@@ -1,15 +1,205 @@
-use ndarray::{Array1, Array2};
-use serde::{Deserialize};
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+use ndarray::{
+    Array, Array2, ArrayBase, ArrayViewMut1, ArrayViewMut2, Axis, Data, DataMut, Ix1, Ix2,
+    RemoveAxis,
+};
+use serde::{Deserialize, Serialize};
+#[cfg(all(feature = "std", feature = "json"))]
 use std::fs::File;
-use std::io::BufReader;
+#[cfg(all(feature = "std", feature = "json"))]
+use std::io::{BufReader, BufWriter, Read};
+#[cfg(all(feature = "std", feature = "json"))]
 use std::path::Path;
 
+#[cfg(feature = "json")]
+use format::ScalerFile;
+use range::{QuantileRangeOverride, DEFAULT_QUANTILE_RANGE};
+
+#[cfg(feature = "arrow")]
+mod arrow;
+mod binarizer;
+#[cfg(feature = "candle")]
+mod candle;
+mod categories;
+mod columns;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+mod diff;
+mod drift;
+mod error;
+mod fixed;
+#[cfg_attr(not(all(feature = "std", feature = "json")), allow(dead_code))] // Used by the JSON IO
+mod format;
+mod function;
+#[cfg(feature = "gpu")]
+mod gpu;
+mod grouped;
+mod iter;
+#[cfg(all(feature = "std", feature = "json"))]
+mod joblib;
+mod label;
+mod merge;
+mod metadata;
+#[cfg(all(feature = "std", feature = "json"))]
+mod mlflow;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "std")]
+mod normal;
+#[cfg(feature = "npy")]
+mod npy;
+mod onehot;
+mod ordinal;
+mod outlier;
+mod pipeline;
+#[cfg(feature = "polars")]
+mod polars;
+#[cfg(feature = "postgres")]
+mod postgres;
+mod range;
+mod rolling;
+#[cfg(feature = "safetensors")]
+mod safetensors;
+mod sample;
+mod series;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(feature = "smartcore")]
+mod smartcore;
+mod statistics;
+mod target;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "tracing")]
+mod trace;
+mod transformer;
+pub mod typestate;
+mod union;
+mod winsorizer;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+pub use binarizer::Binarizer;
+pub use diff::{FeatureDiff, ScalerDiff};
+pub use drift::{DriftReport, DriftThresholds, FeatureDrift};
+pub use error::ScalerError;
+pub use fixed::RobustScalerConst;
+pub use format::FORMAT_VERSION;
+pub use function::FunctionTransformer;
+#[cfg(feature = "gpu")]
+pub use gpu::GpuScaler;
+pub use grouped::{GroupFallback, GroupedRobustScaler};
+pub use iter::TransformIter;
+pub use label::LabelEncoder;
+pub use metadata::ScalerMetadata;
+pub use onehot::{HandleUnknown, OneHotEncoder};
+pub use ordinal::OrdinalEncoder;
+pub use outlier::OutlierFilter;
+pub use pipeline::{Pipeline, PipelineStep};
+pub use range::FeatureKey;
+pub use rolling::RollingRobustScaler;
+#[cfg(feature = "smartcore")]
+pub use smartcore::RobustScalerParameters;
+pub use statistics::FeatureStatistics;
+pub use target::TargetEncoder;
+pub use transformer::Transformer;
+pub use union::FeatureUnion;
+pub use winsorizer::Winsorizer;
+
+/// What `transform` does when it encounters a missing value (NaN).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingPolicy {
+    /// Keep NaN in the output.
+    #[default]
+    Propagate,
+    /// Replace NaN with 0, i.e. the scaled median.
+    Zero,
+    /// Fail, reporting the row and column of the first NaN.
+    Error,
+}
+
+/// How `fit` measures the spread of each feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScaleEstimator {
+    /// Interquartile range (Q3 - Q1), as in scikit-learn.
+    #[default]
+    Iqr,
+    /// Median absolute deviation, times 1.4826 so that it matches the standard
+    /// deviation on normally distributed data.
+    Mad,
+}
+
+/// Consistency factor of the MAD for normally distributed data.
+const MAD_NORMAL_FACTOR: f64 = 1.4826;
+
+impl ScaleEstimator {
+    /// Computes the spread of an already sorted, non-empty feature.
+    ///
+    /// `quantile_range` is in percent and only used by `Iqr`.
+    fn scale_sorted(self, sorted: &[f64], (q_min, q_max): (f64, f64)) -> f64 {
+        match self {
+            ScaleEstimator::Iqr => {
+                quantile_sorted(sorted, q_max / 100.0) - quantile_sorted(sorted, q_min / 100.0)
+            }
+            ScaleEstimator::Mad => {
+                let center = median_sorted(sorted);
+                let mut deviations: Vec<f64> = sorted.iter().map(|x| (x - center).abs()).collect();
+                deviations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                MAD_NORMAL_FACTOR * median_sorted(&deviations)
+            }
+        }
+    }
+}
+
+/// Number of rows handed to each rayon task by the parallel transform.
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK_ROWS: usize = 4096;
+
 /// RobustScaler: A Rust implementation compatible with scikit-learn's RobustScaler.
 /// Centers data using the median and scales using the interquartile range (IQR).
 /// Resistant to outliers.
 pub struct RobustScaler {
-    center_: Vec<f64>,  // Median of each feature
-    scale_: Vec<f64>,   // IQR (Q3 - Q1) of each feature
+    center_: Vec<f64>,                            // Median of each feature
+    scale_: Vec<f64>,                             // IQR (Q3 - Q1) or scaled MAD of each feature
+    statistics_: Option<Vec<FeatureStatistics>>,  // Per-feature summary, if known
+    clip_: Option<(f64, f64)>,                    // Bounds applied to transformed values
+    missing_: MissingPolicy,                      // Handling of NaN at transform time
+    feature_names_: Option<Vec<String>>,          // Name of each feature, if known
+    subsample_: Option<(usize, u64)>,             // Max samples per feature and seed for fit
+    estimator_: ScaleEstimator,                   // Spread measure used by fit
+    quantile_range_: (f64, f64),                  // Quantiles (in %) bounding the IQR
+    quantile_ranges_: Vec<QuantileRangeOverride>, // Per-feature quantile ranges
+    with_centering_: bool,                        // Whether fit computes `center_` (else 0)
+    with_scaling_: bool,                          // Whether fit computes `scale_` (else 1)
+    unit_variance_: bool,                         // Whether fit rescales the IQR to normal data
+    retain_samples_: bool,                        // Whether fit keeps `samples_`
+    save_statistics_: bool,                       // Whether saved files include `statistics_`
+    samples_: Option<Vec<Vec<f64>>>,              // Sorted values of each feature seen by fit
+    metadata_: ScalerMetadata,                    // How the scaler was trained
+}
+
+/// What `fit` learned about a single feature.
+struct FeatureFit {
+    statistics: FeatureStatistics,
+    center: f64,
+    scale: f64,
+    n_samples: usize,          // Before subsampling
+    samples: Option<Vec<f64>>, // Sorted values, if retained
+}
+
+impl Default for RobustScaler {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl RobustScaler {
@@ -18,75 +208,940 @@ impl RobustScaler {
         Self {
             center_: vec![],
             scale_: vec![],
+            statistics_: None,
+            clip_: None,
+            missing_: MissingPolicy::Propagate,
+            feature_names_: None,
+            subsample_: None,
+            estimator_: ScaleEstimator::Iqr,
+            quantile_range_: DEFAULT_QUANTILE_RANGE,
+            quantile_ranges_: vec![],
+            with_centering_: true,
+            with_scaling_: true,
+            unit_variance_: false,
+            retain_samples_: false,
+            save_statistics_: true,
+            samples_: None,
+            metadata_: ScalerMetadata::default(),
+        }
+    }
+
+    /// Returns an unfitted scaler with the same settings (names, clip, quantile
+    /// range, estimator, ...) and metadata.
+    fn unfitted(&self) -> Self {
+        Self {
+            clip_: self.clip_,
+            missing_: self.missing_,
+            feature_names_: self.feature_names_.clone(),
+            subsample_: self.subsample_,
+            estimator_: self.estimator_,
+            quantile_range_: self.quantile_range_,
+            quantile_ranges_: self.quantile_ranges_.clone(),
+            with_centering_: self.with_centering_,
+            with_scaling_: self.with_scaling_,
+            unit_variance_: self.unit_variance_,
+            retain_samples_: self.retain_samples_,
+            save_statistics_: self.save_statistics_,
+            metadata_: self.metadata_.clone(),
+            ..Self::new()
         }
     }
 
+    /// Names the features, so that errors and reports can refer to them.
+    ///
+    /// # Panics
+    /// If the scaler is already fitted and the number of names does not match.
+    pub fn with_feature_names<S: Into<String>>(mut self, names: Vec<S>) -> Self {
+        let names: Vec<String> = names.into_iter().map(Into::into).collect();
+        assert!(
+            self.center_.is_empty() || names.len() == self.n_features(),
+            "Got {} feature names for {} features",
+            names.len(),
+            self.n_features()
+        );
+        self.feature_names_ = Some(names);
+        self
+    }
+
+    /// Returns the name of each feature, if known.
+    pub fn feature_names(&self) -> Option<&[String]> {
+        self.feature_names_.as_deref()
+    }
+
+    /// Returns the column index of the feature called `name`.
+    pub fn feature_index(&self, name: &str) -> Result<usize, ScalerError> {
+        self.feature_names_
+            .as_ref()
+            .and_then(|names| names.iter().position(|n| n == name))
+            .ok_or_else(|| ScalerError::UnknownFeature {
+                name: name.to_string(),
+            })
+    }
+
+    /// Sets what `transform` does with missing values (NaN). Defaults to `Propagate`.
+    pub fn with_missing_policy(mut self, policy: MissingPolicy) -> Self {
+        self.missing_ = policy;
+        self
+    }
+
+    /// Returns the missing-value policy applied at transform time.
+    pub fn missing_policy(&self) -> MissingPolicy {
+        self.missing_
+    }
+
+    /// Saturates transformed values to `[min, max]`.
+    ///
+    /// Useful to keep extreme outliers seen at inference time from blowing up
+    /// downstream models, e.g. `with_clip(-5.0, 5.0)`.
+    ///
+    /// # Panics
+    /// If `min > max` or either bound is NaN.
+    pub fn with_clip(mut self, min: f64, max: f64) -> Self {
+        assert!(min <= max, "Invalid clip range [{}, {}]", min, max);
+        self.clip_ = Some((min, max));
+        self
+    }
+
+    /// Returns the bounds applied to transformed values, if any.
+    pub fn clip(&self) -> Option<(f64, f64)> {
+        self.clip_
+    }
+
+    /// Fits on a uniform random subset of at most `max_samples` rows per feature.
+    ///
+    /// Like scikit-learn's `QuantileTransformer(subsample=...)`, this trades
+    /// exact quantiles for speed on very large datasets. The same `seed` always
+    /// picks the same rows, so fits are reproducible.
+    ///
+    /// # Panics
+    /// If `max_samples` is 0.
+    pub fn with_subsample(mut self, max_samples: usize, seed: u64) -> Self {
+        assert!(max_samples > 0, "max_samples must be positive");
+        self.subsample_ = Some((max_samples, seed));
+        self
+    }
+
+    /// Returns the maximum number of samples per feature and the seed used by `fit`, if set.
+    pub fn subsample(&self) -> Option<(usize, u64)> {
+        self.subsample_
+    }
+
+    /// Sets how `fit` measures the spread of each feature. Defaults to `Iqr`.
+    pub fn with_scale_estimator(mut self, estimator: ScaleEstimator) -> Self {
+        self.estimator_ = estimator;
+        self
+    }
+
+    /// Returns the spread measure used by `fit`.
+    pub fn scale_estimator(&self) -> ScaleEstimator {
+        self.estimator_
+    }
+
+    /// Sets whether `fit` centers the data at the median. If `false`, the center
+    /// of every feature is 0, like scikit-learn's `with_centering=False`.
+    pub fn with_centering(mut self, centering: bool) -> Self {
+        self.with_centering_ = centering;
+        self
+    }
+
+    /// Returns whether `fit` centers the data.
+    pub fn centering(&self) -> bool {
+        self.with_centering_
+    }
+
+    /// Sets whether `fit` scales the data. If `false`, the scale of every
+    /// feature is 1, like scikit-learn's `with_scaling=False`.
+    pub fn with_scaling(mut self, scaling: bool) -> Self {
+        self.with_scaling_ = scaling;
+        self
+    }
+
+    /// Returns whether `fit` scales the data.
+    pub fn scaling(&self) -> bool {
+        self.with_scaling_
+    }
+
+    /// Sets whether `fit` divides the IQR by the width of the same quantile
+    /// range of a standard normal distribution, so that normally distributed
+    /// features end up with a variance of 1, like scikit-learn's `unit_variance=True`.
+    ///
+    /// Only applies to `ScaleEstimator::Iqr`; the MAD is already normalized.
+    #[cfg(feature = "std")]
+    pub fn with_unit_variance(mut self, unit_variance: bool) -> Self {
+        self.unit_variance_ = unit_variance;
+        self
+    }
+
+    /// Returns whether `fit` rescales the IQR to unit variance.
+    pub fn unit_variance(&self) -> bool {
+        self.unit_variance_
+    }
+
+    /// Keeps the sorted values of each feature after `fit` (after subsampling,
+    /// if enabled), so that [`RobustScaler::refit_quantile_range`] can update
+    /// the parameters without the data.
+    ///
+    /// Costs one `f64` per retained value, in memory and in saved files (the
+    /// samples are saved with the scaler, so it can be refitted or merged after
+    /// loading); combine with `with_subsample` on large datasets.
+    pub fn with_retain_samples(mut self, retain: bool) -> Self {
+        self.retain_samples_ = retain;
+        self
+    }
+
+    /// Sets whether saved files (`to_json`, ...) include the per-feature
+    /// statistics recorded by `fit`. Defaults to `true`; the statistics are
+    /// still available through [`RobustScaler::statistics`] either way.
+    pub fn with_save_statistics(mut self, save: bool) -> Self {
+        self.save_statistics_ = save;
+        self
+    }
+
+    /// Recomputes the scale of every feature for a new global quantile range,
+    /// from the samples retained by `fit`. Per-feature ranges still take precedence.
+    ///
+    /// # Panics
+    /// If `fit` did not retain its samples (see [`RobustScaler::with_retain_samples`]),
+    /// or unless `0 <= q_min < q_max <= 100`.
+    pub fn refit_quantile_range(&mut self, q_min: f64, q_max: f64) -> &Self {
+        range::check_quantile_range((q_min, q_max));
+        let samples = self
+            .samples_
+            .take()
+            .expect("No retained samples: fit with `with_retain_samples(true)` first");
+
+        self.quantile_range_ = (q_min, q_max);
+        self.scale_ = samples
+            .iter()
+            .enumerate()
+            .map(|(i, sorted)| self.feature_scale(i, sorted))
+            .collect();
+        self.samples_ = Some(samples);
+        self
+    }
+
+    /// Builds a scaler from already known parameters (e.g. fetched from a feature store).
+    ///
+    /// # Arguments
+    /// * `center` - Median of each feature.
+    /// * `scale` - IQR of each feature; every value must be finite and positive.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)` if the parameters are consistent, `Err(ScalerError)` otherwise.
+    pub fn from_params(center: Vec<f64>, scale: Vec<f64>) -> Result<Self, ScalerError> {
+        check_params(&center, &scale)?;
+        Ok(Self {
+            center_: center,
+            scale_: scale,
+            ..Self::new()
+        })
+    }
+
     /// Fits the scaler to 2D data: computes median and IQR for each feature.
     ///
+    /// Each column is sorted once; min, max, quartiles and count are recorded
+    /// along the way and exposed through [`RobustScaler::statistics`].
+    ///
+    /// A feature with an IQR of zero (a constant column, or any column when
+    /// there is a single row) gets the minimum scale of `1e-8`, so values equal
+    /// to its center are scaled to 0.
+    ///
     /// # Arguments
-    /// * `data` - A 2D array where rows are samples and columns are features.
+    /// * `data` - A 2D array or view (possibly non-contiguous, e.g. every other
+    ///   row) where rows are samples and columns are features.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    ///
+    /// # Panics
+    /// If the data has no rows or no columns, or holds a NaN or infinite value.
+    /// See [`RobustScaler::try_fit`] for a non-panicking version.
+    pub fn fit<S: Data<Elem = f64>>(&mut self, data: &ArrayBase<S, Ix2>) -> &Self {
+        self.try_fit(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fits the scaler to 2D data, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// `Ok(&Self)` for chaining, `ScalerError::EmptyInput` if the data has no
+    /// rows or no columns, `ScalerError::FeatureCountMismatch` if the feature
+    /// names do not match the columns, `ScalerError::FeatureIndexOutOfRange` /
+    /// `ScalerError::UnknownFeature` if a per-feature quantile range refers to a
+    /// feature the data does not have, or `ScalerError::MissingValue` /
+    /// `ScalerError::NonFiniteValue` for the first NaN or infinite value (the
+    /// missing-value policy only applies to `transform`); the scaler is left
+    /// untouched on error.
+    pub fn try_fit<S: Data<Elem = f64>>(
+        &mut self,
+        data: &ArrayBase<S, Ix2>,
+    ) -> Result<&Self, ScalerError> {
+        self.try_fit_axis(data, Axis(0))
+    }
+
+    /// Fits the scaler to 2D data, reporting progress after each feature.
+    ///
+    /// `progress(done, total)` is called with the number of features fitted so
+    /// far; returning `ControlFlow::Break(())` cancels the fit. Features are
+    /// fitted one after another on the calling thread, even with `parallel`.
+    ///
+    /// ```ignore
+    /// scaler.fit_with_progress(&data, |done, total| {
+    ///     eprintln!("fitted {}/{} features", done, total);
+    ///     if interrupted.load(Ordering::Relaxed) {
+    ///         ControlFlow::Break(())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// })?;
+    /// ```
+    ///
+    /// # Returns
+    /// `Ok(&Self)` for chaining, `ScalerError::Cancelled` if `progress` broke
+    /// off, or the errors of [`RobustScaler::try_fit`]; the scaler is left
+    /// untouched on error.
+    pub fn fit_with_progress<S, F>(
+        &mut self,
+        data: &ArrayBase<S, Ix2>,
+        mut progress: F,
+    ) -> Result<&Self, ScalerError>
+    where
+        S: Data<Elem = f64>,
+        F: FnMut(usize, usize) -> ControlFlow<()>,
+    {
+        check_not_empty(data.nrows(), data.ncols())?;
+        self.check_features(data.ncols())?;
+        check_fit_values(self.feature_names_.as_deref(), data.columns())?;
+
+        let total = data.ncols();
+        let mut summaries = Vec::with_capacity(total);
+        for (i, column) in data.columns().into_iter().enumerate() {
+            summaries.push(self.summarize(i, column.to_vec()));
+            if progress(i + 1, total).is_break() {
+                return Err(ScalerError::Cancelled { done: i + 1, total });
+            }
+        }
+        Ok(self.set_parameters(summaries))
+    }
+
+    /// Fits the scaler along the given axis of 2D data.
+    ///
+    /// `Axis(0)` computes statistics down each column (features in columns, as in
+    /// `fit`); `Axis(1)` computes them along each row (features in rows, samples
+    /// in columns), without transposing the data.
     ///
     /// # Returns
     /// &Self for chaining.
-    pub fn fit(&mut self, data: &Array2<f64>) -> &Self {
-        let n_features = data.ncols();
-        self.center_.clear();
-        self.center_.reserve(n_features);
-        self.scale_.clear();
-        self.scale_.reserve(n_features);
+    ///
+    /// # Panics
+    /// If the data has no samples or no features along `axis`, or holds a NaN
+    /// or infinite value.
+    pub fn fit_axis<S: Data<Elem = f64>>(&mut self, data: &ArrayBase<S, Ix2>, axis: Axis) -> &Self {
+        self.try_fit_axis(data, axis)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        for i in 0..n_features {
-            let feature = data.column(i);
-            let center = median(&feature.to_owned());
-            let q1 = quantile(&feature.to_owned(), 0.25);
-            let q3 = quantile(&feature.to_owned(), 0.75);
-            let iqr = (q3 - q1).max(1e-8); // Avoid division by zero
+    /// Fits the scaler along `axis`, checking the data first.
+    fn try_fit_axis<S: Data<Elem = f64>>(
+        &mut self,
+        data: &ArrayBase<S, Ix2>,
+        axis: Axis,
+    ) -> Result<&Self, ScalerError> {
+        let features = match axis.index() {
+            0 => data.columns(),
+            1 => data.rows(),
+            _ => panic!("Axis {} out of bounds for a 2D array", axis.index()),
+        };
+        let n_features = data.len_of(Axis(1 - axis.index()));
+        check_not_empty(data.len_of(axis), n_features)?;
+        self.check_features(n_features)?;
+        check_fit_values(self.feature_names_.as_deref(), features.clone())?;
+        Ok(self.fit_features(features.into_iter().map(|lane| lane.to_vec())))
+    }
 
-            self.center_.push(center);
-            self.scale_.push(iqr);
+    /// Fits the scaler to N-dimensional data, e.g. an `Array3` of shape (batch, time, features).
+    ///
+    /// Every position along `feature_axis` is a feature; all the other axes are
+    /// treated as samples.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    ///
+    /// # Panics
+    /// If the data has no samples or no features, or holds a NaN or infinite value.
+    pub fn fit_nd<D: RemoveAxis>(&mut self, data: &Array<f64, D>, feature_axis: Axis) -> &Self {
+        let n_features = data.len_of(feature_axis);
+        let n_samples = data.len().checked_div(n_features).unwrap_or(0);
+        check_not_empty(n_samples, n_features)
+            .and_then(|()| self.check_features(n_features))
+            .and_then(|()| {
+                check_fit_values(self.feature_names_.as_deref(), data.axis_iter(feature_axis))
+            })
+            .unwrap_or_else(|e| panic!("{}", e));
+
+        let features = data
+            .axis_iter(feature_axis)
+            .map(|feature| feature.iter().copied().collect());
+        self.fit_features(features)
+    }
+
+    /// Checks that the feature names and the per-feature quantile ranges
+    /// match the `n_features` features about to be fitted.
+    fn check_features(&self, n_features: usize) -> Result<(), ScalerError> {
+        if let Some(names) = &self.feature_names_ {
+            if names.len() != n_features {
+                return Err(ScalerError::FeatureCountMismatch {
+                    expected: names.len(),
+                    found: n_features,
+                });
+            }
         }
+        self.check_quantile_range_features(self.feature_names_.as_deref(), n_features)
+    }
+
+    /// Fits one feature per group of values.
+    fn fit_features<I>(&mut self, features: I) -> &Self
+    where
+        I: IntoIterator<Item = Vec<f64>>,
+    {
+        #[cfg(feature = "tracing")]
+        let span = trace::Timed::new(tracing::info_span!(
+            "fit",
+            n_rows = tracing::field::Empty,
+            n_features = tracing::field::Empty,
+            duration_us = tracing::field::Empty
+        ));
+
+        // With the `parallel` feature, features are sorted on the rayon pool.
+        #[cfg(feature = "parallel")]
+        let summaries: Vec<FeatureFit> = {
+            use rayon::prelude::*;
+            let features: Vec<Vec<f64>> = features.into_iter().collect();
+            features
+                .into_par_iter()
+                .enumerate()
+                .map(|(i, values)| self.summarize(i, values))
+                .collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let summaries: Vec<FeatureFit> = features
+            .into_iter()
+            .enumerate()
+            .map(|(i, values)| self.summarize(i, values))
+            .collect();
 
+        #[cfg(feature = "tracing")]
+        {
+            span.record("n_rows", summaries.first().map_or(0, |f| f.n_samples));
+            span.record("n_features", summaries.len());
+        }
+
+        self.set_parameters(summaries)
+    }
+
+    /// Sorts (a subsample of) the values of a feature once, and returns its
+    /// statistics and scale.
+    fn summarize(&self, feature: usize, values: Vec<f64>) -> FeatureFit {
+        let n_samples = values.len();
+        let values = match self.subsample_ {
+            Some((max_samples, seed)) => sample::subsample(values, max_samples, seed),
+            None => values,
+        };
+        let sorted = sorted_vec(values);
+        let statistics = FeatureStatistics::from_sorted(&sorted);
+        FeatureFit {
+            center: if self.with_centering_ {
+                statistics.median
+            } else {
+                0.0
+            },
+            statistics,
+            scale: self.feature_scale(feature, &sorted),
+            n_samples,
+            samples: self.retain_samples_.then_some(sorted),
+        }
+    }
+
+    /// Measures the spread of an already sorted feature, as configured for `fit`.
+    fn feature_scale(&self, feature: usize, sorted: &[f64]) -> f64 {
+        if !self.with_scaling_ {
+            return 1.0;
+        }
+        let quantile_range = self.feature_quantile_range(feature);
+        self.finish_scale(
+            self.estimator_.scale_sorted(sorted, quantile_range),
+            quantile_range,
+        )
+    }
+
+    /// Applies the unit-variance correction and the minimum scale to a measured spread.
+    fn finish_scale(&self, scale: f64, quantile_range: (f64, f64)) -> f64 {
+        let scale = if self.unit_variance_ && self.estimator_ == ScaleEstimator::Iqr {
+            unit_variance_scale(scale, quantile_range)
+        } else {
+            scale
+        };
+        scale.max(1e-8) // Avoid division by zero
+    }
+
+    /// Stores the fitted parameters of every feature.
+    fn set_parameters(&mut self, mut summaries: Vec<FeatureFit>) -> &Self {
+        if let Some(first) = summaries.first() {
+            self.metadata_.record_fit(first.n_samples);
+        }
+        self.center_ = summaries.iter().map(|f| f.center).collect();
+        self.scale_ = summaries.iter().map(|f| f.scale).collect();
+        self.samples_ = self.retain_samples_.then(|| {
+            summaries
+                .iter_mut()
+                .map(|f| f.samples.take().unwrap_or_default())
+                .collect()
+        });
+        self.statistics_ = Some(summaries.into_iter().map(|f| f.statistics).collect());
         self
     }
 
     /// Transforms 2D data using the fitted center and scale.
     ///
     /// # Arguments
-    /// * `data` - Input 2D array to scale.
+    /// * `data` - Input 2D array or view to scale; only the output is allocated.
     ///
     /// # Returns
-    /// Scaled `Array2<f64>`: (X - center) / scale
-    pub fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
-        assert_eq!(data.ncols(), self.center_.len());
-        assert_eq!(data.ncols(), self.scale_.len());
+    /// Scaled `Array2<f64>`: (X - center) / scale, clipped if a range was set.
+    ///
+    /// # Panics
+    /// If [`RobustScaler::validate`] fails. See [`RobustScaler::try_transform`]
+    /// for a non-panicking version.
+    pub fn transform<S: Data<Elem = f64>>(&self, data: &ArrayBase<S, Ix2>) -> Array2<f64> {
+        self.try_transform(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Transforms 2D data, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// `Ok(Array2<f64>)` with the scaled data, or the `ScalerError` reported by
+    /// [`RobustScaler::validate`].
+    pub fn try_transform<S: Data<Elem = f64>>(
+        &self,
+        data: &ArrayBase<S, Ix2>,
+    ) -> Result<Array2<f64>, ScalerError> {
+        #[cfg(feature = "tracing")]
+        let _span = trace::Timed::new(tracing::info_span!(
+            "transform",
+            n_rows = data.nrows(),
+            n_features = data.ncols(),
+            duration_us = tracing::field::Empty
+        ));
+
+        self.validate(data)?;
+
+        let mut result = data.to_owned();
+        self.scale_inplace(&mut result);
+        Ok(result)
+    }
+
+    /// Transforms 2D data in place, without allocating a new array.
+    ///
+    /// # Panics
+    /// If [`RobustScaler::validate`] fails. See [`RobustScaler::try_transform_inplace`]
+    /// for a non-panicking version.
+    pub fn transform_inplace<S: DataMut<Elem = f64>>(&self, data: &mut ArrayBase<S, Ix2>) {
+        self.try_transform_inplace(data)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Transforms 2D data in place, returning an error instead of panicking.
+    ///
+    /// The data is left untouched if validation fails.
+    pub fn try_transform_inplace<S: DataMut<Elem = f64>>(
+        &self,
+        data: &mut ArrayBase<S, Ix2>,
+    ) -> Result<(), ScalerError> {
+        #[cfg(feature = "tracing")]
+        let _span = trace::Timed::new(tracing::info_span!(
+            "transform_inplace",
+            n_rows = data.nrows(),
+            n_features = data.ncols(),
+            duration_us = tracing::field::Empty
+        ));
+
+        self.validate(data)?;
+        self.scale_inplace(data);
+        Ok(())
+    }
+
+    /// Scales already validated data in place.
+    ///
+    /// With the `parallel` feature, blocks of rows are scaled on the rayon pool.
+    fn scale_inplace<S: DataMut<Elem = f64>>(&self, data: &mut ArrayBase<S, Ix2>) {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            data.axis_chunks_iter_mut(Axis(0), PARALLEL_CHUNK_ROWS)
+                .into_par_iter()
+                .for_each(|chunk| self.scale_block(chunk));
+        }
+        #[cfg(not(feature = "parallel"))]
+        self.scale_block(data.view_mut());
+    }
+
+    /// Scales every column of `data` with the parameters of its feature.
+    ///
+    /// Walks the data in memory order: row by row for row-major (C-order)
+    /// data, column by column otherwise (e.g. Fortran-order data).
+    fn scale_block(&self, mut data: ArrayViewMut2<f64>) {
+        if data.ncols() > 1 && data.strides()[1] == 1 {
+            for mut row in data.rows_mut() {
+                for ((x, &center), &scale) in row.iter_mut().zip(&self.center_).zip(&self.scale_) {
+                    *x = self.scale_value(*x, center, scale);
+                }
+            }
+        } else {
+            for (i, (&center, &scale)) in self.center_.iter().zip(&self.scale_).enumerate() {
+                data.column_mut(i)
+                    .mapv_inplace(|x| self.scale_value(x, center, scale));
+            }
+        }
+    }
+
+    /// Checks that `data` can be transformed by this scaler.
+    ///
+    /// Like scikit-learn, infinite values are rejected; NaN is accepted unless
+    /// the missing-value policy is `MissingPolicy::Error`.
+    ///
+    /// # Returns
+    /// `Ok(())`, or the first problem found with its row, column and feature name.
+    pub fn validate<S: Data<Elem = f64>>(
+        &self,
+        data: &ArrayBase<S, Ix2>,
+    ) -> Result<(), ScalerError> {
+        if data.ncols() != self.n_features() {
+            return Err(ScalerError::FeatureCountMismatch {
+                expected: self.n_features(),
+                found: data.ncols(),
+            });
+        }
+        // Scan contiguous data in memory order; only locate the first problem
+        // (in row-major order) if there is one.
+        let nan_allowed = self.missing_ != MissingPolicy::Error;
+        if let Some(values) = data.as_slice_memory_order() {
+            if values
+                .iter()
+                .all(|x| x.is_finite() || (nan_allowed && x.is_nan()))
+            {
+                return Ok(());
+            }
+        }
+        for ((row, column), &x) in data.indexed_iter() {
+            self.check_value(row, column, x)?;
+        }
+        Ok(())
+    }
+
+    /// Checks a single input value against the missing-value policy.
+    fn check_value(&self, row: usize, column: usize, x: f64) -> Result<(), ScalerError> {
+        let feature = || {
+            self.feature_names_
+                .as_ref()
+                .map(|names| names[column].clone())
+        };
+        if x.is_nan() && self.missing_ == MissingPolicy::Error {
+            return Err(ScalerError::MissingValue {
+                row,
+                column,
+                feature: feature(),
+            });
+        }
+        if x.is_infinite() {
+            return Err(ScalerError::NonFiniteValue {
+                row,
+                column,
+                feature: feature(),
+                value: x,
+            });
+        }
+        Ok(())
+    }
+
+    /// Transforms 2D data along the given axis (see [`RobustScaler::fit_axis`]).
+    ///
+    /// With `Axis(1)`, row `i` is scaled with the parameters of feature `i`.
+    ///
+    /// # Panics
+    /// If [`RobustScaler::try_transform_axis`] fails.
+    pub fn transform_axis<S: Data<Elem = f64>>(
+        &self,
+        data: &ArrayBase<S, Ix2>,
+        axis: Axis,
+    ) -> Array2<f64> {
+        self.try_transform_axis(data, axis)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Transforms 2D data along the given axis, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// The scaled data, or the `ScalerError` reported by [`RobustScaler::validate`]
+    /// for the data seen along `axis` (with `Axis(1)`, errors report the sample
+    /// as `row` and the feature as `column`).
+    pub fn try_transform_axis<S: Data<Elem = f64>>(
+        &self,
+        data: &ArrayBase<S, Ix2>,
+        axis: Axis,
+    ) -> Result<Array2<f64>, ScalerError> {
+        match axis.index() {
+            0 => self.try_transform(data),
+            1 => {
+                if data.nrows() != self.n_features() {
+                    return Err(ScalerError::FeatureCountMismatch {
+                        expected: self.n_features(),
+                        found: data.nrows(),
+                    });
+                }
+                // Samples are columns here, features are rows.
+                for ((column, row), &x) in data.indexed_iter() {
+                    self.check_value(row, column, x)?;
+                }
+
+                let mut result = data.to_owned();
+                for (i, (&center, &scale)) in self.center_.iter().zip(&self.scale_).enumerate() {
+                    result
+                        .row_mut(i)
+                        .mapv_inplace(|x| self.scale_value(x, center, scale));
+                }
+                Ok(result)
+            }
+            _ => panic!("Axis {} out of bounds for a 2D array", axis.index()),
+        }
+    }
+
+    /// Transforms N-dimensional data, broadcasting center and scale over every
+    /// axis except `feature_axis`.
+    ///
+    /// # Arguments
+    /// * `data` - Input array, e.g. an `Array3` of shape (batch, time, features).
+    /// * `feature_axis` - Axis holding the features (`Axis(2)` in the example above).
+    ///
+    /// # Panics
+    /// If `try_transform_nd` fails.
+    pub fn transform_nd<D: RemoveAxis>(
+        &self,
+        data: &Array<f64, D>,
+        feature_axis: Axis,
+    ) -> Array<f64, D> {
+        self.try_transform_nd(data, feature_axis)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Transforms N-dimensional data, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// The scaled data, `ScalerError::FeatureCountMismatch` if `feature_axis` is
+    /// not as long as the number of features, or the `MissingValue` /
+    /// `NonFiniteValue` errors of [`RobustScaler::validate`]. These report the
+    /// feature as `column` and, as `row`, the position of the value among the
+    /// values of its feature (in the logical order of the other axes).
+    pub fn try_transform_nd<D: RemoveAxis>(
+        &self,
+        data: &Array<f64, D>,
+        feature_axis: Axis,
+    ) -> Result<Array<f64, D>, ScalerError> {
+        self.check_nd(data, feature_axis)?;
+        for (column, feature) in data.axis_iter(feature_axis).enumerate() {
+            for (row, &x) in feature.iter().enumerate() {
+                self.check_value(row, column, x)?;
+            }
+        }
 
         let mut result = data.clone();
-        for (i, (center, scale)) in self.center_.iter().zip(&self.scale_).enumerate() {
-            result.column_mut(i).mapv_inplace(|x| (x - center) / scale);
+        for (mut feature, (&center, &scale)) in result
+            .axis_iter_mut(feature_axis)
+            .zip(self.center_.iter().zip(&self.scale_))
+        {
+            feature.mapv_inplace(|x| self.scale_value(x, center, scale));
         }
-        result
+        Ok(result)
+    }
+
+    /// Reverts [`RobustScaler::transform_nd`].
+    ///
+    /// # Panics
+    /// If `try_inverse_transform_nd` fails.
+    pub fn inverse_transform_nd<D: RemoveAxis>(
+        &self,
+        data: &Array<f64, D>,
+        feature_axis: Axis,
+    ) -> Array<f64, D> {
+        self.try_inverse_transform_nd(data, feature_axis)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reverts [`RobustScaler::transform_nd`], returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// The unscaled data, or `ScalerError::FeatureCountMismatch` if `feature_axis`
+    /// is not as long as the number of features.
+    pub fn try_inverse_transform_nd<D: RemoveAxis>(
+        &self,
+        data: &Array<f64, D>,
+        feature_axis: Axis,
+    ) -> Result<Array<f64, D>, ScalerError> {
+        self.check_nd(data, feature_axis)?;
+
+        let mut result = data.clone();
+        for (mut feature, (&center, &scale)) in result
+            .axis_iter_mut(feature_axis)
+            .zip(self.center_.iter().zip(&self.scale_))
+        {
+            feature.mapv_inplace(|x| x * scale + center);
+        }
+        Ok(result)
+    }
+
+    fn check_nd<D: RemoveAxis>(
+        &self,
+        data: &Array<f64, D>,
+        feature_axis: Axis,
+    ) -> Result<(), ScalerError> {
+        if data.len_of(feature_axis) != self.n_features() {
+            return Err(ScalerError::FeatureCountMismatch {
+                expected: self.n_features(),
+                found: data.len_of(feature_axis),
+            });
+        }
+        Ok(())
+    }
+
+    /// Fits the scaler and transforms the data along the given axis in one step.
+    pub fn fit_transform_axis<S: Data<Elem = f64>>(
+        &mut self,
+        data: &ArrayBase<S, Ix2>,
+        axis: Axis,
+    ) -> Array2<f64> {
+        self.fit_axis(data, axis);
+        self.transform_axis(data, axis)
     }
 
     /// Transforms a 1D input vector (commonly used in APIs).
     ///
+    /// Output is narrowed to `f32`; use [`RobustScaler::transform_1d_f64`] to
+    /// keep full precision.
+    ///
     /// # Arguments
     /// * `input` - Slice of feature values (length must match `n_features`).
     ///
     /// # Returns
     /// A `Vec<f32>` with scaled values.
     pub fn transform_1d(&self, input: &[f64]) -> Vec<f32> {
+        self.transform_1d_f64(input)
+            .into_iter()
+            .map(|x| x as f32)
+            .collect()
+    }
+
+    /// Transforms a 1D input vector, keeping `f64` precision.
+    ///
+    /// # Arguments
+    /// * `input` - Slice of feature values (length must match `n_features`).
+    ///
+    /// # Returns
+    /// A `Vec<f64>` with scaled values.
+    pub fn transform_1d_f64(&self, input: &[f64]) -> Vec<f64> {
         if input.len() != self.center_.len() {
             panic!("Input size {} does not match scaler n_features {}", input.len(), self.center_.len());
         }
+        for (column, &x) in input.iter().enumerate() {
+            if let Err(e) = self.check_value(0, column, x) {
+                panic!("{}", e);
+            }
+        }
 
         input
             .iter()
             .zip(&self.center_)
             .zip(&self.scale_)
-            .map(|((&x, &center), &scale)| ((x - center) / scale) as f32)
+            .map(|((&x, &center), &scale)| self.scale_value(x, center, scale))
             .collect()
     }
 
+    /// Transforms rows given as slices (e.g. a `Vec<Vec<f64>>` from a JSON request)
+    /// without building an `Array2`.
+    ///
+    /// # Panics
+    /// If a row fails validation. See [`RobustScaler::try_transform_rows`].
+    pub fn transform_rows<R: AsRef<[f64]>>(&self, rows: &[R]) -> Vec<Vec<f64>> {
+        self.try_transform_rows(rows)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Transforms rows given as slices, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// `Ok(Vec<Vec<f64>>)` with one scaled row per input row, or the first
+    /// `ScalerError` found, with the index of the offending row.
+    pub fn try_transform_rows<R: AsRef<[f64]>>(
+        &self,
+        rows: &[R],
+    ) -> Result<Vec<Vec<f64>>, ScalerError> {
+        rows.iter()
+            .enumerate()
+            .map(|(i, row)| self.try_transform_row(i, row.as_ref()))
+            .collect()
+    }
+
+    /// Validates and transforms a single row.
+    fn try_transform_row(&self, row: usize, values: &[f64]) -> Result<Vec<f64>, ScalerError> {
+        if values.len() != self.n_features() {
+            return Err(ScalerError::RowLengthMismatch {
+                row,
+                expected: self.n_features(),
+                found: values.len(),
+            });
+        }
+        for (column, &x) in values.iter().enumerate() {
+            self.check_value(row, column, x)?;
+        }
+
+        Ok(values
+            .iter()
+            .zip(&self.center_)
+            .zip(&self.scale_)
+            .map(|((&x, &center), &scale)| self.scale_value(x, center, scale))
+            .collect())
+    }
+
+    /// Reverts the scaling: X * scale + center.
+    ///
+    /// Values that were clipped during `transform` are not recovered.
+    ///
+    /// # Arguments
+    /// * `data` - Scaled 2D array.
+    ///
+    /// # Returns
+    /// `Array2<f64>` in the original feature space.
+    pub fn inverse_transform<S: Data<Elem = f64>>(&self, data: &ArrayBase<S, Ix2>) -> Array2<f64> {
+        assert_eq!(data.ncols(), self.center_.len());
+        assert_eq!(data.ncols(), self.scale_.len());
+
+        let mut result = data.to_owned();
+        for (i, (center, scale)) in self.center_.iter().zip(&self.scale_).enumerate() {
+            result.column_mut(i).mapv_inplace(|x| x * scale + center);
+        }
+        result
+    }
+
+    /// Scales a single value, applying the missing-value policy and the clip range.
+    fn scale_value(&self, x: f64, center: f64, scale: f64) -> f64 {
+        let scaled = if x.is_nan() && self.missing_ == MissingPolicy::Zero {
+            0.0
+        } else {
+            (x - center) / scale
+        };
+        match self.clip_ {
+            Some((min, max)) => scaled.clamp(min, max),
+            None => scaled,
+        }
+    }
+
     /// Fits the scaler and transforms the data in one step.
     ///
     /// # Arguments
@@ -94,9 +1149,54 @@ impl RobustScaler {
     ///
     /// # Returns
     /// Scaled `Array2<f64>`.
-    pub fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
-        self.fit(data);
-        self.transform(data)
+    ///
+    /// # Panics
+    /// If the data has no rows or no columns, or holds a NaN or infinite value,
+    /// as for [`RobustScaler::fit`]; the scaler is left untouched in that case.
+    pub fn fit_transform<S: Data<Elem = f64>>(&mut self, data: &ArrayBase<S, Ix2>) -> Array2<f64> {
+        // Checked before anything is sorted or scaled
+        check_not_empty(data.nrows(), data.ncols())
+            .and_then(|()| self.check_features(data.ncols()))
+            .and_then(|()| check_fit_values(self.feature_names_.as_deref(), data.columns()))
+            .unwrap_or_else(|e| panic!("{}", e));
+        #[cfg(feature = "tracing")]
+        let _span = trace::Timed::new(tracing::info_span!(
+            "fit_transform",
+            n_rows = data.nrows(),
+            n_features = data.ncols(),
+            duration_us = tracing::field::Empty
+        ));
+
+        // Each column is sorted once and scaled while it is still in cache,
+        // instead of a full `fit` pass followed by a full `transform` pass.
+        let mut result = data.to_owned();
+        let fit_and_scale = |(i, mut column): (usize, ArrayViewMut1<f64>)| {
+            let fit = self.summarize(i, column.to_vec());
+            let (center, scale) = (fit.center, fit.scale);
+            column.mapv_inplace(|x| self.scale_value(x, center, scale));
+            fit
+        };
+
+        #[cfg(feature = "parallel")]
+        let summaries: Vec<FeatureFit> = {
+            use rayon::prelude::*;
+            result
+                .axis_iter_mut(Axis(1))
+                .into_par_iter()
+                .enumerate()
+                .map(fit_and_scale)
+                .collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let summaries: Vec<FeatureFit> = result
+            .columns_mut()
+            .into_iter()
+            .enumerate()
+            .map(fit_and_scale)
+            .collect();
+
+        self.set_parameters(summaries);
+        result
     }
 
     /// Loads a pre-trained RobustScaler from a JSON file (exported from scikit-learn).
@@ -106,69 +1206,260 @@ impl RobustScaler {
     /// - "scale_": list of IQRs
     /// - "n_features_in_": number of features
     ///
+    /// Files written by [`RobustScaler::to_json`] also carry a `"format_version"`;
+    /// every version up to [`FORMAT_VERSION`] is accepted, and files from a newer
+    /// version are rejected with `ScalerError::UnsupportedFormatVersion`.
+    ///
     /// # Arguments
     /// * `path` - Path to the JSON file.
     ///
     /// # Returns
     /// `Ok(RobustScaler)` if successful, `Err` otherwise.
+    #[cfg(all(feature = "std", feature = "json"))]
     pub fn from_json<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        #[cfg(feature = "tracing")]
+        let span = trace::Timed::new(tracing::info_span!(
+            "from_json",
+            path = %path.as_ref().display(),
+            n_features = tracing::field::Empty,
+            duration_us = tracing::field::Empty
+        ));
+
         let file = File::open(path.as_ref())?;
-        let reader = BufReader::new(file);
+        let scaler = Self::from_json_reader(BufReader::new(file))?;
 
-        let params: SklearnRobustScalerParams = serde_json::from_reader(reader)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        #[cfg(feature = "tracing")]
+        span.record("n_features", scaler.n_features());
 
-        if params.center.len() != params.n_features_in {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "Length of 'center_' does not match 'n_features_in_'",
-            ));
-        }
-        if params.scale.len() != params.n_features_in {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "Length of 'scale_' does not match 'n_features_in_'",
-            ));
-        }
+        Ok(scaler)
+    }
 
-        Ok(Self {
-            center_: params.center,
-            scale_: params.scale,
-        })
+    /// Loads a scaler from any reader of JSON (a network stream, an object-storage
+    /// download, ...), using the same schema as [`RobustScaler::from_json`].
+    ///
+    /// The reader is not buffered; wrap it in a `BufReader` if it does small reads.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, or `Err` with `ErrorKind::InvalidData` if the JSON is
+    /// not a valid scaler.
+    #[cfg(all(feature = "std", feature = "json"))]
+    pub fn from_json_reader<R: Read>(reader: R) -> std::io::Result<Self> {
+        let value: serde_json::Value = serde_json::from_reader(reader).map_err(invalid_data)?;
+
+        Ok(ScalerFile::from_value(value)?.into_scaler()?)
+    }
+
+    /// Loads a scaler from JSON bytes, using the same schema as [`RobustScaler::from_json`].
+    ///
+    /// Does not need `std`, so parameters can be baked into firmware with
+    /// `include_bytes!("scaler.json")`.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, or `ScalerError::InvalidFile` if the bytes are not a valid scaler.
+    #[cfg(feature = "json")]
+    pub fn from_json_slice(bytes: &[u8]) -> Result<Self, ScalerError> {
+        let value: serde_json::Value =
+            serde_json::from_slice(bytes).map_err(|e| ScalerError::InvalidFile {
+                message: e.to_string(),
+            })?;
+
+        ScalerFile::from_value(value)?.into_scaler()
+    }
+
+    /// Loads a scaler from a JSON string, using the same schema as [`RobustScaler::from_json`].
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, or `ScalerError::InvalidFile` if the string is not a valid scaler.
+    #[cfg(feature = "json")]
+    pub fn from_json_str(json: &str) -> Result<Self, ScalerError> {
+        Self::from_json_slice(json.as_bytes())
+    }
+
+    /// Loads a scaler from the JSON stored in an environment variable, for
+    /// deployments that inject parameters instead of mounting files.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the variable, e.g. `"SCALER_PARAMS"`.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, `Err` with `ErrorKind::NotFound` if the variable is
+    /// not set (or not valid Unicode), or `ErrorKind::InvalidData` if it does
+    /// not hold a valid scaler.
+    #[cfg(all(feature = "std", feature = "json"))]
+    pub fn from_env(name: &str) -> std::io::Result<Self> {
+        let json = std::env::var(name).map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, format!("{}: {}", name, e))
+        })?;
+
+        Ok(Self::from_json_str(&json)?)
+    }
+
+    /// Saves the scaler to a JSON file using the same schema as [`RobustScaler::from_json`].
+    ///
+    /// The file is written in the current [`FORMAT_VERSION`] and keeps the
+    /// scikit-learn keys at the top level.
+    ///
+    /// Per-feature statistics are included under `"statistics"` when the scaler
+    /// was fitted in Rust (or loaded from a file that contained them), unless
+    /// disabled with [`RobustScaler::with_save_statistics`].
+    ///
+    /// # Arguments
+    /// * `path` - Path of the JSON file to create.
+    #[cfg(all(feature = "std", feature = "json"))]
+    pub fn to_json<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let file = File::create(path.as_ref())?;
+        let writer = BufWriter::new(file);
+
+        serde_json::to_writer_pretty(writer, &ScalerFile::from_scaler(self)).map_err(invalid_data)
     }
 
     /// Returns the number of features the scaler was trained on.
     pub fn n_features(&self) -> usize {
         self.center_.len()
     }
+
+    /// Returns the fitted center (median) of each feature.
+    pub fn center(&self) -> &[f64] {
+        &self.center_
+    }
+
+    /// Returns the fitted scale (IQR, or scaled MAD) of each feature.
+    pub fn scale(&self) -> &[f64] {
+        &self.scale_
+    }
+
+    /// Returns the per-feature statistics recorded during `fit`.
+    ///
+    /// `None` if the scaler was built from parameters that did not include them.
+    pub fn statistics(&self) -> Option<&[FeatureStatistics]> {
+        self.statistics_.as_deref()
+    }
+}
+
+impl Transformer for RobustScaler {
+    fn fit(&mut self, data: &Array2<f64>) {
+        RobustScaler::fit(self, data);
+    }
+
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        RobustScaler::transform(self, data)
+    }
+
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        RobustScaler::inverse_transform(self, data)
+    }
+
+    fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        RobustScaler::fit_transform(self, data)
+    }
 }
 
 // --- Helper functions ---
 
-/// Computes the median of a 1D array.
-fn median(data: &Array1<f64>) -> f64 {
+/// Divides an IQR by the distance between the same quantiles of a standard normal.
+#[cfg(feature = "std")]
+fn unit_variance_scale(iqr: f64, quantile_range: (f64, f64)) -> f64 {
+    iqr / normal::normal_quantile_spread(quantile_range)
+}
+
+/// The normal quantile function needs `ln` and `sqrt`, which are only available with `std`.
+#[cfg(not(feature = "std"))]
+fn unit_variance_scale(_iqr: f64, _quantile_range: (f64, f64)) -> f64 {
+    panic!("Fitting with `unit_variance` needs the `std` feature")
+}
+
+/// Rejects data without samples or features before fitting.
+fn check_not_empty(n_samples: usize, n_features: usize) -> Result<(), ScalerError> {
+    if n_samples == 0 || n_features == 0 {
+        return Err(ScalerError::EmptyInput {
+            n_samples,
+            n_features,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that center and scale have the same length, every center is finite
+/// and every scale is finite and positive.
+fn check_params(center: &[f64], scale: &[f64]) -> Result<(), ScalerError> {
+    if center.len() != scale.len() {
+        return Err(ScalerError::LengthMismatch {
+            center: center.len(),
+            scale: scale.len(),
+        });
+    }
+    if let Some((feature, &value)) = center.iter().enumerate().find(|(_, c)| !c.is_finite()) {
+        return Err(ScalerError::InvalidCenter { feature, value });
+    }
+    if let Some((feature, &value)) = scale
+        .iter()
+        .enumerate()
+        .find(|(_, s)| !(s.is_finite() && **s > 0.0))
+    {
+        return Err(ScalerError::InvalidScale { feature, value });
+    }
+    Ok(())
+}
+
+/// Checks that the values of every feature are finite, since NaN cannot be
+/// sorted and infinity has no median or quantile. Errors report the sample
+/// index as `row` and the feature index as `column`.
+fn check_fit_values<'a, I, F>(names: Option<&[String]>, features: I) -> Result<(), ScalerError>
+where
+    I: IntoIterator<Item = F>,
+    F: IntoIterator<Item = &'a f64>,
+{
+    for (column, values) in features.into_iter().enumerate() {
+        if let Some((row, &x)) = values.into_iter().enumerate().find(|(_, x)| !x.is_finite()) {
+            let feature = names.and_then(|names| names.get(column).cloned());
+            return Err(if x.is_nan() {
+                ScalerError::MissingValue {
+                    row,
+                    column,
+                    feature,
+                }
+            } else {
+                ScalerError::NonFiniteValue {
+                    row,
+                    column,
+                    feature,
+                    value: x,
+                }
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Sorts the values of a feature in place.
+fn sorted_vec(mut values: Vec<f64>) -> Vec<f64> {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    values
+}
+
+/// Returns a sorted copy of a 1D array or view.
+fn sorted<S: Data<Elem = f64>>(data: &ArrayBase<S, Ix1>) -> Vec<f64> {
     let mut sorted = data.to_vec();
     sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted
+}
+
+/// Computes the median of an already sorted slice.
+fn median_sorted(sorted: &[f64]) -> f64 {
     let n = sorted.len();
-    if n % 2 == 0 {
+    if n.is_multiple_of(2) {
         (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
     } else {
         sorted[n / 2]
     }
 }
 
-/// Computes the quantile of a 1D array using linear interpolation.
-///
-/// # Arguments
-/// * `data` - Input array.
-/// * `q` - Quantile value (0.0 ≤ q ≤ 1.0).
-fn quantile(data: &Array1<f64>, q: f64) -> f64 {
-    assert!(q >= 0.0 && q <= 1.0);
-    let mut sorted = data.to_vec();
-    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+/// Computes the quantile of an already sorted slice using linear interpolation.
+fn quantile_sorted(sorted: &[f64], q: f64) -> f64 {
+    assert!((0.0..=1.0).contains(&q));
     let n = sorted.len() as f64;
     let index = q * (n - 1.0);
-    let i = index.floor() as usize;
+    let i = index as usize; // Truncation is `floor` for a non-negative index
     let t = index - i as f64;
 
     if i >= sorted.len() - 1 {
@@ -178,17 +1469,13 @@ fn quantile(data: &Array1<f64>, q: f64) -> f64 {
     }
 }
 
-// Parameters deserialized from scikit-learn's JSON
-#[derive(Deserialize)]
-struct SklearnRobustScalerParams {
-    #[serde(rename = "center_")]
-    center: Vec<f64>,
-
-    #[serde(rename = "scale_")]
-    scale: Vec<f64>,
-
-    #[serde(rename = "n_features_in_")]
-    n_features_in: usize,
+/// Wraps a parsing or validation failure into an `InvalidData` IO error.
+#[cfg(any(all(feature = "std", feature = "json"), feature = "npy"))]
+fn invalid_data<E>(error: E) -> std::io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    std::io::Error::new(std::io::ErrorKind::InvalidData, error)
 }
 
 // --- Tests ---
@@ -209,8 +1496,485 @@ mod tests {
 
         let input = vec![1.0, 2.0];
         let scaled = scaler.transform_1d(&input);
-        // For first feature: median = 3, Q1 = 1, Q3 = 5 → IQR = 4 → (1-3)/4 = -0.5
-        // But with only 3 points, quantiles may vary slightly
-        assert!((scaled[0] - (-0.5) as f32).abs() < 1e-5);
+        // For first feature: median = 3, Q1 = 2, Q3 = 4 (linear interpolation)
+        // → IQR = 2 → (1-3)/2 = -1.0, matching sklearn
+        assert!((scaled[0] - -1.0_f32).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_transform_1d_f64() {
+        let scaler = RobustScaler::from_params(vec![0.0], vec![1.0]).unwrap();
+        // 1e9 + 1 is not representable as f32.
+        assert_eq!(scaler.transform_1d_f64(&[1e9 + 1.0]), vec![1e9 + 1.0]);
+        assert_eq!(scaler.transform_1d(&[1e9 + 1.0]), vec![1e9_f32]);
+    }
+
+    #[test]
+    fn test_transform_rows() {
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0]).unwrap();
+        let rows = vec![vec![3.0, 6.0], vec![1.0, 2.0]];
+        assert_eq!(
+            scaler.transform_rows(&rows),
+            vec![vec![1.0, 1.0], vec![0.0, 0.0]]
+        );
+
+        let rows: [&[f64]; 2] = [&[3.0, 6.0], &[1.0]];
+        assert_eq!(
+            scaler.try_transform_rows(&rows),
+            Err(ScalerError::RowLengthMismatch {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_transform_inplace() {
+        let mut scaler = RobustScaler::new();
+        let mut data = Array2::from_shape_fn((10_000, 3), |(i, j)| (i * (j + 1)) as f64);
+        let expected = scaler.fit_transform(&data);
+
+        scaler.transform_inplace(&mut data);
+        assert_eq!(data, expected);
+        assert!(scaler
+            .try_transform_inplace(&mut arr2(&[[1.0, 2.0]]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_params() {
+        let scaler = RobustScaler::from_params(vec![3.0, 4.0], vec![2.0, 0.5]).unwrap();
+        assert_eq!(scaler.center(), &[3.0, 4.0]);
+        assert_eq!(scaler.scale(), &[2.0, 0.5]);
+        assert_eq!(scaler.transform_1d(&[1.0, 5.0]), vec![-1.0, 2.0]);
+
+        assert_eq!(
+            RobustScaler::from_params(vec![0.0], vec![1.0, 1.0]).err(),
+            Some(ScalerError::LengthMismatch {
+                center: 1,
+                scale: 2
+            })
+        );
+        assert_eq!(
+            RobustScaler::from_params(vec![0.0, 0.0], vec![1.0, 0.0]).err(),
+            Some(ScalerError::InvalidScale {
+                feature: 1,
+                value: 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_subsample() {
+        let data = Array2::from_shape_fn((10_000, 2), |(i, j)| (i * (j + 1)) as f64);
+        let mut scaler = RobustScaler::new().with_subsample(1000, 0);
+        scaler.fit(&data);
+
+        assert_eq!(scaler.statistics().unwrap()[0].count, 1000);
+        assert!((scaler.center()[0] - 5000.0).abs() < 500.0);
+        // Both columns keep the same rows
+        assert_eq!(scaler.center()[1], 2.0 * scaler.center()[0]);
+    }
+
+    #[test]
+    fn test_mad_estimator() {
+        let data = arr2(&[[1.0], [2.0], [3.0], [4.0], [100.0]]);
+        let mut scaler = RobustScaler::new().with_scale_estimator(ScaleEstimator::Mad);
+        scaler.fit(&data);
+
+        // Deviations from the median 3 are [2, 1, 0, 1, 97], whose median is 1
+        assert_eq!(scaler.center(), &[3.0]);
+        assert!((scaler.scale()[0] - 1.4826).abs() < 1e-12);
+        assert!(!scaler.check_drift(&data).has_drift());
+    }
+
+    #[test]
+    fn test_fit_empty_and_single_row() {
+        let mut scaler = RobustScaler::from_params(vec![1.0], vec![2.0]).unwrap();
+        assert_eq!(
+            scaler.try_fit(&Array2::zeros((0, 2))).err(),
+            Some(ScalerError::EmptyInput {
+                n_samples: 0,
+                n_features: 2
+            })
+        );
+        assert_eq!(
+            scaler.try_fit(&Array2::zeros((3, 0))).err(),
+            Some(ScalerError::EmptyInput {
+                n_samples: 3,
+                n_features: 0
+            })
+        );
+        assert_eq!(scaler.center(), &[1.0]);
+
+        let data = arr2(&[[1.0, -2.0]]);
+        scaler.try_fit(&data).unwrap();
+        assert_eq!(scaler.center(), &[1.0, -2.0]);
+        assert_eq!(scaler.scale(), &[1e-8, 1e-8]);
+        assert_eq!(scaler.transform(&data), arr2(&[[0.0, 0.0]]));
+    }
+
+    #[test]
+    fn test_fit_non_finite() {
+        let mut scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 3.0])
+            .unwrap()
+            .with_feature_names(vec!["a", "b"]);
+        assert_eq!(
+            scaler.try_fit(&arr2(&[[1.0, 2.0], [3.0, f64::NAN]])).err(),
+            Some(ScalerError::MissingValue {
+                row: 1,
+                column: 1,
+                feature: Some("b".into())
+            })
+        );
+        assert_eq!(
+            scaler.try_fit(&arr2(&[[f64::NEG_INFINITY, 2.0]])).err(),
+            Some(ScalerError::NonFiniteValue {
+                row: 0,
+                column: 0,
+                feature: Some("a".into()),
+                value: f64::NEG_INFINITY
+            })
+        );
+        assert_eq!(
+            scaler.try_fit(&arr2(&[[1.0, 2.0, 3.0]])).err(),
+            Some(ScalerError::FeatureCountMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(scaler.center(), &[1.0, 2.0]);
+        assert!(scaler.statistics().is_none());
+    }
+
+    #[test]
+    fn test_fit_with_progress() {
+        let data = arr2(&[[1.0, 10.0, 100.0], [3.0, 30.0, 300.0]]);
+        let mut calls = vec![];
+        let mut scaler = RobustScaler::new();
+        scaler
+            .fit_with_progress(&data, |done, total| {
+                calls.push((done, total));
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(calls, vec![(1, 3), (2, 3), (3, 3)]);
+        assert_eq!(scaler.center(), &[2.0, 20.0, 200.0]);
+
+        let mut cancelled = RobustScaler::from_params(vec![0.0], vec![1.0]).unwrap();
+        let err = cancelled.fit_with_progress(&data, |done, _| {
+            if done == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(
+            err.err(),
+            Some(ScalerError::Cancelled { done: 2, total: 3 })
+        );
+        assert_eq!(cancelled.center(), &[0.0]);
+    }
+
+    #[test]
+    fn test_centering_scaling_unit_variance() {
+        let data = arr2(&[[1.0], [2.0], [3.0], [4.0], [5.0]]);
+
+        let mut scaler = RobustScaler::new().with_centering(false);
+        scaler.fit(&data);
+        assert_eq!((scaler.center(), scaler.scale()), (&[0.0][..], &[2.0][..]));
+
+        let mut scaler = RobustScaler::new().with_scaling(false);
+        scaler.fit(&data);
+        assert_eq!((scaler.center(), scaler.scale()), (&[3.0][..], &[1.0][..]));
+
+        #[cfg(feature = "std")]
+        {
+            let mut scaler = RobustScaler::new().with_unit_variance(true);
+            scaler.fit(&data);
+            assert!((scaler.scale()[0] - 2.0 / 1.3489795003921634).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_transform_memory_layouts() {
+        use ndarray::ShapeBuilder;
+
+        let data = arr2(&[[1.0, 10.0, 5.0], [2.0, 20.0, 7.0], [4.0, 40.0, 9.0]]);
+        let mut fortran = Array2::zeros((3, 3).f());
+        fortran.assign(&data);
+        let mut scaler = RobustScaler::new();
+        scaler.fit(&data);
+
+        let expected = scaler.transform(&data);
+        assert_eq!(scaler.transform(&fortran), expected);
+
+        fortran[[2, 1]] = f64::INFINITY;
+        assert!(matches!(
+            scaler.try_transform(&fortran),
+            Err(ScalerError::NonFiniteValue {
+                row: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_views() {
+        let data = arr2(&[
+            [1.0, 10.0],
+            [0.0, 0.0],
+            [2.0, 30.0],
+            [0.0, 0.0],
+            [4.0, 20.0],
+        ]);
+        let every_other_row = data.slice(ndarray::s![..;2, ..]);
+        let copy = every_other_row.to_owned();
+
+        let mut scaler = RobustScaler::new();
+        scaler.fit(&every_other_row);
+        assert_eq!(scaler.center(), &[2.0, 20.0]);
+        assert_eq!(scaler.transform(&every_other_row), scaler.transform(&copy));
+
+        let mut inplace = data.clone();
+        scaler.transform_inplace(&mut inplace.slice_mut(ndarray::s![..;2, ..]));
+        assert_eq!(inplace.row(0), scaler.transform(&copy).row(0));
+        assert_eq!(inplace.row(1), data.row(1));
+    }
+
+    #[test]
+    fn test_fit_transform() {
+        let data = arr2(&[[1.0, 8.0], [5.0, 2.0], [3.0, 100.0], [7.0, 4.0]]);
+        let mut scaler = RobustScaler::new()
+            .with_clip(-1.0, 1.0)
+            .with_scale_estimator(ScaleEstimator::Mad);
+        let scaled = scaler.fit_transform(&data);
+
+        let mut reference = RobustScaler::new()
+            .with_clip(-1.0, 1.0)
+            .with_scale_estimator(ScaleEstimator::Mad);
+        reference.fit(&data);
+        assert_eq!(scaled, reference.transform(&data));
+        assert_eq!(scaler.statistics(), reference.statistics());
+
+        // Invalid data is rejected before the scaler is refitted
+        let invalid = arr2(&[[1.0, f64::INFINITY], [2.0, 3.0]]);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            scaler.fit_transform(&invalid);
+        }));
+        assert!(result.is_err());
+        assert_eq!(scaler.statistics(), reference.statistics());
+    }
+
+    #[test]
+    fn test_refit_quantile_range() {
+        let data = Array2::from_shape_fn((101, 1), |(i, _)| i as f64);
+        let mut scaler = RobustScaler::new().with_retain_samples(true);
+        scaler.fit(&data);
+        assert_eq!(scaler.scale(), &[50.0]);
+
+        scaler.refit_quantile_range(10.0, 90.0);
+        assert_eq!(scaler.scale(), &[80.0]);
+        assert_eq!(scaler.center(), &[50.0]);
+        assert_eq!(scaler.quantile_range(), (10.0, 90.0));
+    }
+
+    #[test]
+    fn test_clip() {
+        let scaler = RobustScaler::from_params(vec![0.0], vec![1.0])
+            .unwrap()
+            .with_clip(-3.0, 3.0);
+        let scaled = scaler.transform(&arr2(&[[-10.0], [1.5], [100.0]]));
+        assert_eq!(scaled, arr2(&[[-3.0], [1.5], [3.0]]));
+        assert_eq!(scaler.transform_1d(&[7.0]), vec![3.0]);
+    }
+
+    #[test]
+    fn test_inverse_transform() {
+        let mut scaler = RobustScaler::new();
+        let data = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let scaled = scaler.fit_transform(&data);
+        assert_eq!(scaler.inverse_transform(&scaled), data);
+    }
+
+    #[test]
+    fn test_axis() {
+        let data = arr2(&[[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+        let mut by_row = RobustScaler::new();
+        let scaled = by_row.fit_transform_axis(&data, Axis(1));
+
+        let mut by_column = RobustScaler::new();
+        let expected = by_column.fit_transform(&data.t().to_owned());
+        assert_eq!(by_row.center(), by_column.center());
+        assert_eq!(scaled, expected.t());
+        assert_eq!(
+            by_row.try_transform_axis(&data.t(), Axis(1)),
+            Err(ScalerError::FeatureCountMismatch {
+                expected: data.nrows(),
+                found: data.ncols()
+            })
+        );
+    }
+
+    #[test]
+    fn test_nd() {
+        // (batch, time, features) = (2, 2, 2)
+        let data = ndarray::arr3(&[[[1.0, 10.0], [2.0, 20.0]], [[3.0, 30.0], [4.0, 40.0]]]);
+        let mut scaler = RobustScaler::new();
+        scaler.fit_nd(&data, Axis(2));
+
+        let flat = data.clone().into_shape_with_order((4, 2)).unwrap();
+        let mut flat_scaler = RobustScaler::new();
+        let expected = flat_scaler.fit_transform(&flat);
+        assert_eq!(scaler.center(), flat_scaler.center());
+
+        let scaled = scaler.transform_nd(&data.clone().into_dyn(), Axis(2));
+        assert_eq!(scaled.into_shape_with_order((4, 2)).unwrap(), expected);
+        assert_eq!(
+            scaler.inverse_transform_nd(&scaler.transform_nd(&data, Axis(2)), Axis(2)),
+            data
+        );
+
+        let one_feature = data.slice(ndarray::s![.., .., ..1]).to_owned();
+        assert_eq!(
+            scaler.try_inverse_transform_nd(&one_feature, Axis(2)).err(),
+            Some(ScalerError::FeatureCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        let mut infinite = data.clone();
+        infinite[[1, 0, 1]] = f64::INFINITY;
+        assert_eq!(
+            scaler.try_transform_nd(&infinite, Axis(2)).err(),
+            Some(ScalerError::NonFiniteValue {
+                row: 2,
+                column: 1,
+                feature: None,
+                value: f64::INFINITY
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_policy() {
+        let data = arr2(&[[1.0, f64::NAN], [3.0, 4.0]]);
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 2.0]).unwrap();
+        assert!(scaler.transform(&data)[[0, 1]].is_nan());
+
+        let zero = scaler.with_missing_policy(MissingPolicy::Zero);
+        assert_eq!(zero.transform(&data), arr2(&[[0.0, 0.0], [1.0, 1.0]]));
+
+        let error = zero.with_missing_policy(MissingPolicy::Error);
+        assert_eq!(
+            error.try_transform(&data).err(),
+            Some(ScalerError::MissingValue {
+                row: 0,
+                column: 1,
+                feature: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let scaler = RobustScaler::from_params(vec![0.0, 0.0], vec![1.0, 1.0])
+            .unwrap()
+            .with_feature_names(vec!["age", "price"]);
+
+        let err = scaler
+            .validate(&arr2(&[[1.0, 2.0], [3.0, f64::INFINITY]]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Non-finite value inf at row 1, column 1 ('price')"
+        );
+        assert_eq!(
+            scaler.validate(&arr2(&[[1.0, 2.0, 3.0]])),
+            Err(ScalerError::FeatureCountMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(scaler.feature_index("price"), Ok(1));
+        assert!(scaler.feature_index("height").is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "json"))]
+    fn test_json_round_trip() {
+        let mut scaler = RobustScaler::new()
+            .with_clip(-2.0, 2.0)
+            .with_missing_policy(MissingPolicy::Zero)
+            .with_feature_names(vec!["a", "b"])
+            .with_feature_quantile_range("b", 5.0, 95.0)
+            .with_scale_estimator(ScaleEstimator::Mad)
+            .with_subsample(1000, 7)
+            .with_tag("dataset", "test");
+        scaler.fit(&arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]));
+
+        let path = std::env::temp_dir().join("robust_scaler_test_json_round_trip.json");
+        scaler.to_json(&path).unwrap();
+        let loaded = RobustScaler::from_json(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.center(), scaler.center());
+        assert_eq!(loaded.scale(), scaler.scale());
+        assert_eq!(loaded.statistics(), scaler.statistics());
+        assert_eq!(loaded.clip(), scaler.clip());
+        assert_eq!(loaded.missing_policy(), scaler.missing_policy());
+        assert_eq!(loaded.feature_names(), scaler.feature_names());
+        assert_eq!(loaded.feature_quantile_range(1), (5.0, 95.0));
+        assert_eq!(loaded.scale_estimator(), ScaleEstimator::Mad);
+        assert_eq!(loaded.subsample(), Some((1000, 7)));
+        assert_eq!(loaded.metadata(), scaler.metadata());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_from_json_slice() {
+        let json = br#"{"center_": [1.0], "scale_": [2.0], "n_features_in_": 1}"#;
+        let scaler = RobustScaler::from_json_slice(json).unwrap();
+        assert_eq!(scaler.transform_1d(&[5.0]), vec![2.0]);
+
+        assert!(matches!(
+            RobustScaler::from_json_slice(b"{}"),
+            Err(ScalerError::InvalidFile { .. })
+        ));
+    }
+
+    #[cfg(all(feature = "std", feature = "json"))]
+    #[test]
+    fn test_from_json_reader() {
+        let json = br#"{"center_": [1.0], "scale_": [2.0], "n_features_in_": 1}"#;
+        let scaler = RobustScaler::from_json_reader(std::io::Cursor::new(json)).unwrap();
+        assert_eq!(scaler.scale(), &[2.0]);
+
+        let error = RobustScaler::from_json_reader(&b"not json"[..])
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(all(feature = "std", feature = "json"))]
+    #[test]
+    fn test_from_env() {
+        let name = "ROBUST_SCALER_TEST_FROM_ENV";
+        let error = RobustScaler::from_env(name).err().unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+
+        std::env::set_var(
+            name,
+            r#"{"center_": [1.0], "scale_": [2.0], "n_features_in_": 1}"#,
+        );
+        let scaler = RobustScaler::from_env(name).unwrap();
+        assert_eq!(scaler.center(), &[1.0]);
+
+        std::env::set_var(name, "{}");
+        let error = RobustScaler::from_env(name).err().unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        std::env::remove_var(name);
     }
 }
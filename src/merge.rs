@@ -0,0 +1,126 @@
+use alloc::vec::Vec;
+
+use crate::{RobustScaler, ScalerError};
+
+impl RobustScaler {
+    /// Combines scalers fitted on shards of the same dataset (e.g. by separate
+    /// workers) into one scaler, as if it had been fitted on all the shards.
+    ///
+    /// Every shard must have been fitted with `with_retain_samples(true)`; the
+    /// merged scaler is refitted from the concatenation of their retained
+    /// samples and retains it too, so merges can be nested. The settings
+    /// (quantile range, names, clip, ...) are taken from the first shard.
+    ///
+    /// This merges raw samples, not fixed-size quantile sketches: the retained
+    /// samples (saved with the scaler by e.g. `to_json`, so shards can be fitted
+    /// on other machines) take memory and file space in proportion to the rows
+    /// of each shard, and a merged scaler holds the samples of all its shards.
+    /// Fit large shards with `with_subsample` to bound each of them to
+    /// `max_samples` values per feature.
+    ///
+    /// The result is exact without subsampling. With `with_subsample` it is
+    /// approximate, and every shard weighs as much as the samples it retained.
+    ///
+    /// # Returns
+    /// The merged scaler, `ScalerError::NoRetainedSamples` if a shard did not
+    /// retain its samples, `ScalerError::FeatureCountMismatch` if the shards do
+    /// not have the same features, or `ScalerError::EmptyInput` if there are no
+    /// shards or no retained value of some feature.
+    pub fn merge(shards: &[RobustScaler]) -> Result<RobustScaler, ScalerError> {
+        let first = shards.first().ok_or(ScalerError::EmptyInput {
+            n_samples: 0,
+            n_features: 0,
+        })?;
+        let n_features = first.n_features();
+
+        let mut features: Vec<Vec<f64>> = (0..n_features).map(|_| Vec::new()).collect();
+        for (shard, scaler) in shards.iter().enumerate() {
+            let samples = scaler
+                .samples_
+                .as_ref()
+                .ok_or(ScalerError::NoRetainedSamples { shard })?;
+            if samples.len() != n_features {
+                return Err(ScalerError::FeatureCountMismatch {
+                    expected: n_features,
+                    found: samples.len(),
+                });
+            }
+            for (feature, values) in features.iter_mut().zip(samples) {
+                feature.extend_from_slice(values);
+            }
+        }
+        if features.iter().any(Vec::is_empty) {
+            return Err(ScalerError::EmptyInput {
+                n_samples: 0,
+                n_features,
+            });
+        }
+
+        let mut merged = Self {
+            retain_samples_: true,
+            ..first.unfitted()
+        };
+        merged.fit_features(features);
+        merged.metadata_.n_samples_seen = shards
+            .iter()
+            .map(|s| s.metadata_.n_samples_seen)
+            .sum::<Option<usize>>();
+
+        Ok(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::{arr2, concatenate, Axis};
+
+    #[test]
+    fn test_merge() {
+        let a = arr2(&[[1.0, -5.0], [2.0, 0.0], [7.0, 3.0]]);
+        let b = arr2(&[[4.0, 8.0], [5.0, 1.0], [100.0, 2.0], [3.0, 4.0]]);
+        let shards: Vec<RobustScaler> = [&a, &b]
+            .iter()
+            .map(|shard| {
+                let mut scaler = RobustScaler::new().with_retain_samples(true);
+                scaler.fit(*shard);
+                scaler
+            })
+            .collect();
+
+        // A shard saved by one worker and loaded by another
+        #[cfg(feature = "json")]
+        let shards: Vec<RobustScaler> = shards
+            .iter()
+            .map(|shard| {
+                let file = serde_json::to_vec(&crate::format::ScalerFile::from_scaler(shard));
+                RobustScaler::from_json_slice(&file.unwrap()).unwrap()
+            })
+            .collect();
+
+        let merged = RobustScaler::merge(&shards).unwrap();
+        let mut full = RobustScaler::new();
+        full.fit(&concatenate(Axis(0), &[a.view(), b.view()]).unwrap());
+
+        assert_eq!(merged.center(), full.center());
+        assert_eq!(merged.scale(), full.scale());
+        assert_eq!(merged.metadata().n_samples_seen, Some(7));
+
+        let unretained = RobustScaler::from_params(vec![0.0, 0.0], vec![1.0, 1.0]).unwrap();
+        assert_eq!(
+            RobustScaler::merge(&[merged, unretained]).err(),
+            Some(ScalerError::NoRetainedSamples { shard: 1 })
+        );
+
+        let mut empty = RobustScaler::from_params(vec![0.0], vec![1.0]).unwrap();
+        empty.samples_ = Some(vec![vec![]]);
+        assert_eq!(
+            RobustScaler::merge(&[empty]).err(),
+            Some(ScalerError::EmptyInput {
+                n_samples: 0,
+                n_features: 1
+            })
+        );
+    }
+}
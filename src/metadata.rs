@@ -0,0 +1,80 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+use crate::RobustScaler;
+
+/// Information about how a scaler was trained, saved alongside its parameters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScalerMetadata {
+    /// Number of samples per feature passed to `fit` (before subsampling).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n_samples_seen: Option<usize>,
+    /// End of the last `fit`, in seconds since the Unix epoch (needs `std`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fitted_at: Option<u64>,
+    /// Version of this crate that ran the last `fit`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crate_version: Option<String>,
+    /// User-supplied key/value tags, e.g. the dataset or the git commit.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+}
+
+impl ScalerMetadata {
+    /// Returns `true` if nothing is known (used to skip serializing it).
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Records a fit over `n_samples` samples per feature.
+    pub(crate) fn record_fit(&mut self, n_samples: usize) {
+        self.n_samples_seen = Some(n_samples);
+        self.crate_version = Some(env!("CARGO_PKG_VERSION").into());
+        #[cfg(feature = "std")]
+        {
+            use std::time::{SystemTime, UNIX_EPOCH};
+            self.fitted_at = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|elapsed| elapsed.as_secs());
+        }
+    }
+}
+
+impl RobustScaler {
+    /// Adds a tag to the metadata saved with the scaler, e.g. `with_tag("dataset", "2024-06")`.
+    pub fn with_tag<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.metadata_.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns what is known about how the scaler was trained.
+    ///
+    /// Filled by `fit` and kept by `to_json` / `from_json` (and the binary formats).
+    pub fn metadata(&self) -> &ScalerMetadata {
+        &self.metadata_
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_metadata() {
+        let mut scaler = RobustScaler::new().with_tag("dataset", "june");
+        assert_eq!(scaler.metadata().n_samples_seen, None);
+
+        scaler.fit(&arr2(&[[1.0], [2.0], [3.0]]));
+        let metadata = scaler.metadata();
+        assert_eq!(metadata.n_samples_seen, Some(3));
+        assert_eq!(
+            metadata.crate_version.as_deref(),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(metadata.tags["dataset"], "june");
+    }
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::path::Path;
+
+use crate::{RobustScaler, FORMAT_VERSION};
+
+/// Python loader of the `python_function` flavor, run by MLflow.
+const PYFUNC_LOADER: &str = include_str!("mlflow_pyfunc.py");
+
+/// Name of the scaler artifact inside the model directory.
+const DATA_FILE: &str = "robust_scaler.json";
+
+impl RobustScaler {
+    /// Saves the scaler as an MLflow model directory, which can be logged with
+    /// `mlflow.log_artifacts` and registered like a Python model.
+    ///
+    /// The directory holds the scaler JSON (see [`RobustScaler::to_json`]), an
+    /// `MLmodel` file describing it in a `robust_scaler` flavor and in the
+    /// `python_function` flavor, and a bundled Python loader, so that
+    /// `mlflow.pyfunc.load_model(dir).predict(data)` scales data with numpy.
+    ///
+    /// # Arguments
+    /// * `dir` - Model directory; created if needed, existing files are overwritten.
+    ///
+    /// # Returns
+    /// `Ok(())` if successful, `Err` otherwise.
+    pub fn export_mlflow<P: AsRef<Path>>(&self, dir: P) -> std::io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir.join("code"))?;
+
+        self.to_json(dir.join(DATA_FILE))?;
+        fs::write(
+            dir.join("code").join("robust_scaler_pyfunc.py"),
+            PYFUNC_LOADER,
+        )?;
+        fs::write(dir.join("requirements.txt"), "numpy\n")?;
+        fs::write(dir.join("MLmodel"), ml_model())
+    }
+}
+
+/// Contents of the `MLmodel` file.
+fn ml_model() -> String {
+    format!(
+        "flavors:\n\
+         \x20 python_function:\n\
+         \x20   code: code\n\
+         \x20   data: {data}\n\
+         \x20   loader_module: robust_scaler_pyfunc\n\
+         \x20 robust_scaler:\n\
+         \x20   crate_version: {version}\n\
+         \x20   data: {data}\n\
+         \x20   format_version: {format}\n",
+        data = DATA_FILE,
+        version = env!("CARGO_PKG_VERSION"),
+        format = FORMAT_VERSION
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn test_export_mlflow() {
+        let dir = std::env::temp_dir().join("robust_scaler_test_export_mlflow");
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0]).unwrap();
+        scaler.export_mlflow(&dir).unwrap();
+
+        let ml_model = fs::read_to_string(dir.join("MLmodel")).unwrap();
+        assert!(ml_model.contains("    loader_module: robust_scaler_pyfunc\n"));
+        let loaded = RobustScaler::from_json(dir.join(DATA_FILE)).unwrap();
+        assert_eq!(loaded.center(), scaler.center());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Runs the loader the way MLflow does, with
+    /// `cargo test -- --ignored` where `python3` can import numpy.
+    #[test]
+    #[ignore = "needs python3 with numpy"]
+    fn test_mlflow_pyfunc() {
+        let dir = std::env::temp_dir().join("robust_scaler_test_mlflow_pyfunc");
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0])
+            .unwrap()
+            .with_missing_policy(crate::MissingPolicy::Zero);
+        scaler.export_mlflow(&dir).unwrap();
+
+        let script = "import sys\n\
+            sys.path.insert(0, sys.argv[1] + '/code')\n\
+            import robust_scaler_pyfunc\n\
+            model = robust_scaler_pyfunc._load_pyfunc(sys.argv[1] + '/robust_scaler.json')\n\
+            print(model.predict([[3.0, float('nan')]]).tolist())\n\
+            try: model.predict([[float('inf'), 1.0]])\n\
+            except ValueError: print('rejected')";
+        let output = Command::new("python3")
+            .arg("-c")
+            .arg(script)
+            .arg(&dir)
+            .output()
+            .expect("python3 is needed to run this test");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "[[1.0, 0.0]]\nrejected"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+"""MLflow `python_function` loader for a scaler exported by robust_scaler.
+
+Written to `code/robust_scaler_pyfunc.py` by `RobustScaler::export_mlflow`.
+`mlflow.pyfunc.load_model` calls `_load_pyfunc` with the path of the scaler
+JSON artifact; `predict` then applies the same transform as the Rust crate.
+"""
+import json
+
+import numpy as np
+
+
+class RobustScalerModel:
+    def __init__(self, params):
+        options = params.get("options", {})
+        self.center = np.asarray(params["center_"], dtype=float)
+        self.scale = np.asarray(params["scale_"], dtype=float)
+        self.feature_names = params.get("feature_names_in_")
+        self.clip = options.get("clip")
+        self.missing_policy = options.get("missing_policy", "propagate")
+
+    def predict(self, model_input, params=None):
+        # DataFrames are matched to the features by name.
+        if self.feature_names is not None and hasattr(model_input, "columns"):
+            model_input = model_input[self.feature_names]
+        data = np.asarray(model_input, dtype=float)
+        if data.shape[-1] != len(self.center):
+            raise ValueError(
+                "Input has %d features but the scaler expects %d"
+                % (data.shape[-1], len(self.center))
+            )
+        # Infinite values are rejected whatever the policy, as in Rust.
+        if np.isinf(data).any():
+            raise ValueError("Input contains non-finite values")
+        if self.missing_policy == "error" and np.isnan(data).any():
+            raise ValueError("Input contains missing values")
+
+        scaled = (data - self.center) / self.scale
+        if self.missing_policy == "zero":
+            scaled = np.where(np.isnan(scaled), 0.0, scaled)
+        if self.clip is not None:
+            scaled = np.clip(scaled, self.clip[0], self.clip[1])
+        return scaled
+
+
+def _load_pyfunc(path):
+    with open(path) as f:
+        return RobustScalerModel(json.load(f))
@@ -0,0 +1,55 @@
+use alloc::vec::Vec;
+
+use crate::format::{scaler_from_document, ScalerFile};
+use crate::{RobustScaler, ScalerError};
+
+impl RobustScaler {
+    /// Serializes the scaler to MessagePack (enabled by the `msgpack` feature).
+    ///
+    /// Uses the same schema as [`RobustScaler::to_json`], with named fields, so
+    /// the blob decodes to the same document in any MessagePack tool.
+    pub fn to_msgpack(&self) -> Vec<u8> {
+        // MessagePack can encode every map, sequence, string and number, and
+        // writing to a `Vec` does not fail
+        rmp_serde::to_vec_named(&ScalerFile::from_scaler(self))
+            .expect("A scaler file is representable in MessagePack")
+    }
+
+    /// Loads a scaler from MessagePack written by [`RobustScaler::to_msgpack`]
+    /// (or any tool using the JSON schema).
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, or `ScalerError::InvalidFile` if the bytes are not a valid scaler.
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, ScalerError> {
+        scaler_from_document(rmp_serde::from_slice(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_msgpack_round_trip() {
+        let mut scaler = RobustScaler::new()
+            .with_clip(-3.0, 3.0)
+            .with_feature_names(vec!["a", "b"]);
+        scaler.fit(&arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]]));
+
+        let bytes = scaler.to_msgpack();
+        let loaded = RobustScaler::from_msgpack(&bytes).unwrap();
+        assert_eq!(loaded.center(), scaler.center());
+        assert_eq!(loaded.clip(), scaler.clip());
+
+        // Fields are encoded by name, not by position
+        let document: serde_json::Value = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(document["feature_names_in_"][1], "b");
+
+        // A blob cut off in the middle is reported, not read as a shorter scaler
+        assert!(matches!(
+            RobustScaler::from_msgpack(&bytes[..bytes.len() / 2]),
+            Err(ScalerError::InvalidFile { .. })
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+#![allow(clippy::excessive_precision)]
+
+/// Quantile function (inverse CDF) of the standard normal distribution.
+///
+/// Wichura's algorithm AS 241, accurate to about 1e-16, like
+/// `scipy.stats.norm.ppf`.
+pub(crate) fn normal_ppf(p: f64) -> f64 {
+    let q = p - 0.5;
+    if q.abs() <= 0.425 {
+        let r = 0.180625 - q * q;
+        return q * polynomial(r, &A) / polynomial(r, &B);
+    }
+
+    let r = if q < 0.0 { p } else { 1.0 - p };
+    let r = (-r.ln()).sqrt();
+    let value = if r <= 5.0 {
+        let r = r - 1.6;
+        polynomial(r, &C) / polynomial(r, &D)
+    } else {
+        let r = r - 5.0;
+        polynomial(r, &E) / polynomial(r, &F)
+    };
+    if q < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Distance between two quantiles (in percent) of the standard normal distribution.
+pub(crate) fn normal_quantile_spread((q_min, q_max): (f64, f64)) -> f64 {
+    normal_ppf(q_max / 100.0) - normal_ppf(q_min / 100.0)
+}
+
+/// Evaluates a polynomial with the given coefficients, lowest degree first.
+fn polynomial(x: f64, coefficients: &[f64; 8]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+// Coefficients as published, with more digits than an f64 holds
+const A: [f64; 8] = [
+    3.387132872796366608,
+    133.14166789178437745,
+    1971.5909503065514427,
+    13731.693765509461125,
+    45921.953931549871457,
+    67265.770927008700853,
+    33430.575583588128105,
+    2509.0809287301226727,
+];
+const B: [f64; 8] = [
+    1.0,
+    42.313330701600911252,
+    687.1870074920579083,
+    5394.1960214247511077,
+    21213.794301586595867,
+    39307.89580009271061,
+    28729.085735721942674,
+    5226.495278852854561,
+];
+const C: [f64; 8] = [
+    1.42343711074968357734,
+    4.6303378461565452959,
+    5.7694972214606914055,
+    3.64784832476320460504,
+    1.27045825245236838258,
+    0.24178072517745061177,
+    0.0227238449892691845833,
+    7.7454501427834140764e-4,
+];
+const D: [f64; 8] = [
+    1.0,
+    2.05319162663775882187,
+    1.6763848301838038494,
+    0.68976733498510000455,
+    0.14810397642748007459,
+    0.0151986665636164571966,
+    5.475938084995344946e-4,
+    1.05075007164441684324e-9,
+];
+const E: [f64; 8] = [
+    6.6579046435011037772,
+    5.4637849111641143699,
+    1.7848265399172913358,
+    0.29656057182850489123,
+    0.026532189526576123093,
+    0.0012426609473880784386,
+    2.71155556874348757815e-5,
+    2.01033439929228813265e-7,
+];
+const F: [f64; 8] = [
+    1.0,
+    0.59983220655588793769,
+    0.13692988092273580531,
+    0.0148753612908506148525,
+    7.868691311456132591e-4,
+    1.8463183175100546818e-5,
+    1.4215117583164458887e-7,
+    2.04426310338993978564e-15,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_ppf() {
+        // Reference values from Python's `statistics.NormalDist().inv_cdf`
+        let cases = [
+            (0.75, 0.6744897501960817),
+            (0.975, 1.9599639845400536),
+            (0.1, -1.2815515655446008),
+            (1e-10, -6.361340902404056),
+        ];
+        for (p, expected) in cases {
+            assert!((normal_ppf(p) - expected).abs() < 1e-15 * expected.abs().max(1.0));
+        }
+        assert_eq!(normal_ppf(0.5), 0.0);
+    }
+}
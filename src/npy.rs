@@ -0,0 +1,84 @@
+use std::fs::File;
+use std::path::Path;
+
+use ndarray::Array1;
+use ndarray_npy::{read_npy, NpzReader};
+
+use crate::{invalid_data, RobustScaler};
+
+impl RobustScaler {
+    /// Loads a pre-trained RobustScaler from a NumPy `.npz` archive.
+    ///
+    /// Reads the arrays as exact float64 values, without the round-trip through
+    /// JSON text. The archive is expected to be written with:
+    ///
+    /// ```python
+    /// np.savez("robust_scaler.npz", center_=scaler.center_, scale_=scaler.scale_)
+    /// ```
+    ///
+    /// # Arguments
+    /// * `path` - Path to the `.npz` file, containing `center_` and `scale_` arrays.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)` if successful, `Err` otherwise.
+    pub fn from_npz<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let file = File::open(path.as_ref())?;
+        let mut npz = NpzReader::new(file).map_err(invalid_data)?;
+
+        let center: Array1<f64> = npz.by_name("center_").map_err(invalid_data)?;
+        let scale: Array1<f64> = npz.by_name("scale_").map_err(invalid_data)?;
+
+        Ok(Self::from_params(center.to_vec(), scale.to_vec())?)
+    }
+
+    /// Loads a pre-trained RobustScaler from two NumPy `.npy` files.
+    ///
+    /// # Arguments
+    /// * `center_path` - `.npy` file written with `np.save(path, scaler.center_)`.
+    /// * `scale_path` - `.npy` file written with `np.save(path, scaler.scale_)`.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)` if successful, `Err` otherwise.
+    pub fn from_npy<P: AsRef<Path>, Q: AsRef<Path>>(
+        center_path: P,
+        scale_path: Q,
+    ) -> std::io::Result<Self> {
+        let center: Array1<f64> = read_npy(center_path).map_err(invalid_data)?;
+        let scale: Array1<f64> = read_npy(scale_path).map_err(invalid_data)?;
+
+        Ok(Self::from_params(center.to_vec(), scale.to_vec())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr1;
+    use ndarray_npy::{write_npy, NpzWriter};
+
+    #[test]
+    fn test_from_npz_and_npy() {
+        let center = arr1(&[0.1, 1e300]);
+        let scale = arr1(&[1.0 / 3.0, 2.5]);
+        let dir = std::env::temp_dir();
+
+        let npz_path = dir.join("robust_scaler_test_from_npz.npz");
+        let mut npz = NpzWriter::new(File::create(&npz_path).unwrap());
+        npz.add_array("center_", &center).unwrap();
+        npz.add_array("scale_", &scale).unwrap();
+        npz.finish().unwrap();
+        let scaler = RobustScaler::from_npz(&npz_path).unwrap();
+        std::fs::remove_file(&npz_path).unwrap();
+        assert_eq!(scaler.center(), center.as_slice().unwrap());
+        assert_eq!(scaler.scale(), scale.as_slice().unwrap());
+
+        let center_path = dir.join("robust_scaler_test_from_npy_center.npy");
+        let scale_path = dir.join("robust_scaler_test_from_npy_scale.npy");
+        write_npy(&center_path, &center).unwrap();
+        write_npy(&scale_path, &scale).unwrap();
+        let scaler = RobustScaler::from_npy(&center_path, &scale_path).unwrap();
+        std::fs::remove_file(&center_path).unwrap();
+        std::fs::remove_file(&scale_path).unwrap();
+        assert_eq!(scaler.scale(), scale.as_slice().unwrap());
+    }
+}
@@ -0,0 +1,281 @@
+use alloc::vec::Vec;
+use ndarray::Array2;
+
+use crate::categories::Categories;
+use crate::ScalerError;
+
+/// What `OneHotEncoder::transform` does with categories not seen during `fit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HandleUnknown {
+    /// Fail with `ScalerError::UnknownCategory`.
+    #[default]
+    Error,
+    /// Encode the value as all zeros.
+    Ignore,
+}
+
+/// OneHotEncoder: encodes each categorical column as one 0/1 column per category.
+///
+/// Categories are learned per column during `fit` (sorted, like scikit-learn's
+/// `categories='auto'`) or given with [`OneHotEncoder::from_categories`].
+/// Works with any ordered category type, e.g. `String` or `i64`.
+pub struct OneHotEncoder<T> {
+    handle_unknown: HandleUnknown,
+    drop_first: bool,
+    categories_: Categories<T>, // Categories of each column, in output order
+    drop_idx_: Option<Vec<Option<usize>>>, // Dropped category of each column, if imported
+}
+
+impl<T: Ord + Clone> Default for OneHotEncoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> OneHotEncoder<T> {
+    /// Creates a new unfitted OneHotEncoder failing on unknown categories.
+    pub fn new() -> Self {
+        Self {
+            handle_unknown: HandleUnknown::default(),
+            drop_first: false,
+            categories_: Categories::empty(),
+            drop_idx_: None,
+        }
+    }
+
+    /// Builds a fitted encoder from known categories (e.g. scikit-learn's `categories_`).
+    ///
+    /// # Panics
+    /// If a column lists the same category twice. See
+    /// [`OneHotEncoder::try_from_categories`] for a non-panicking version.
+    pub fn from_categories(categories: Vec<Vec<T>>) -> Self {
+        Self::try_from_categories(categories).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds a fitted encoder from known categories, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// The encoder, or `ScalerError::DuplicateCategory` if a column lists the
+    /// same category twice.
+    pub fn try_from_categories(categories: Vec<Vec<T>>) -> Result<Self, ScalerError> {
+        Ok(Self {
+            categories_: Categories::new(categories)?,
+            ..Self::new()
+        })
+    }
+
+    /// Sets what to do with unknown categories. Defaults to `Error`.
+    pub fn with_handle_unknown(mut self, handle_unknown: HandleUnknown) -> Self {
+        self.handle_unknown = handle_unknown;
+        self
+    }
+
+    /// Drops the column of the first category of each feature (`drop='first'`),
+    /// which is then encoded as all zeros.
+    pub fn with_drop_first(mut self, drop_first: bool) -> Self {
+        self.drop_first = drop_first;
+        self.drop_idx_ = None;
+        self
+    }
+
+    /// Returns the unknown-category handling.
+    pub fn handle_unknown(&self) -> HandleUnknown {
+        self.handle_unknown
+    }
+
+    /// Learns the categories of each column.
+    ///
+    /// # Arguments
+    /// * `data` - A 2D array where rows are samples and columns are categorical features.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, data: &Array2<T>) -> &Self {
+        self.categories_ = Categories::fit(data);
+        self
+    }
+
+    /// Encodes `data`, panicking on unknown categories under `HandleUnknown::Error`.
+    ///
+    /// # Returns
+    /// An `Array2<f64>` with [`OneHotEncoder::n_output_features`] columns.
+    pub fn transform(&self, data: &Array2<T>) -> Array2<f64> {
+        self.try_transform(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Encodes `data`, returning an error instead of panicking.
+    pub fn try_transform(&self, data: &Array2<T>) -> Result<Array2<f64>, ScalerError> {
+        if data.ncols() != self.categories_.len() {
+            return Err(ScalerError::FeatureCountMismatch {
+                expected: self.categories_.len(),
+                found: data.ncols(),
+            });
+        }
+
+        let mut result = Array2::zeros((data.nrows(), self.n_output_features()));
+        for (row, (values, mut output)) in
+            data.rows().into_iter().zip(result.rows_mut()).enumerate()
+        {
+            let mut offset = 0;
+            for (column, value) in values.iter().enumerate() {
+                let dropped = self.dropped(column);
+                match self.categories_.position(column, value) {
+                    Some(k) if Some(k) == dropped => {}
+                    Some(k) => {
+                        let k = if dropped.is_some_and(|d| k > d) {
+                            k - 1
+                        } else {
+                            k
+                        };
+                        output[offset + k] = 1.0;
+                    }
+                    None if self.handle_unknown == HandleUnknown::Ignore => {}
+                    None => return Err(ScalerError::UnknownCategory { row, column }),
+                }
+                offset += self.n_columns(column);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Learns the categories and encodes the data in one step.
+    pub fn fit_transform(&mut self, data: &Array2<T>) -> Array2<f64> {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// Returns the categories of each column, in output order.
+    pub fn categories(&self) -> &[Vec<T>] {
+        self.categories_.as_slice()
+    }
+
+    /// Returns the number of columns produced by `transform`.
+    pub fn n_output_features(&self) -> usize {
+        (0..self.categories_.len()).map(|c| self.n_columns(c)).sum()
+    }
+
+    /// Index of the dropped category of `column`, if any.
+    fn dropped(&self, column: usize) -> Option<usize> {
+        match &self.drop_idx_ {
+            Some(drop_idx) => drop_idx[column],
+            None => {
+                (self.drop_first && !self.categories_.as_slice()[column].is_empty()).then_some(0)
+            }
+        }
+    }
+
+    /// Number of output columns of `column`.
+    fn n_columns(&self, column: usize) -> usize {
+        self.categories_.as_slice()[column].len() - usize::from(self.dropped(column).is_some())
+    }
+}
+
+#[cfg(all(feature = "std", feature = "json"))]
+impl<T: Ord + Clone + serde::de::DeserializeOwned> OneHotEncoder<T> {
+    /// Loads an encoder exported from scikit-learn as JSON.
+    ///
+    /// Expects a JSON with:
+    /// - "categories_": list of category lists, one per column
+    /// - "drop_idx_" (optional): index of the dropped category of each column, or null
+    /// - "handle_unknown" (optional): "error" or "ignore"
+    ///
+    /// # Returns
+    /// `Ok(OneHotEncoder)` if successful, `Err` otherwise.
+    pub fn from_json<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        #[derive(serde::Deserialize)]
+        struct OneHotFile<T> {
+            #[serde(rename = "categories_")]
+            categories: Vec<Vec<T>>,
+            #[serde(rename = "drop_idx_", default)]
+            drop_idx: Option<Vec<Option<usize>>>,
+            #[serde(default)]
+            handle_unknown: Option<String>,
+        }
+
+        let reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        let file: OneHotFile<T> = serde_json::from_reader(reader).map_err(crate::invalid_data)?;
+
+        let handle_unknown = match file.handle_unknown.as_deref() {
+            None | Some("error") => HandleUnknown::Error,
+            Some("ignore") | Some("infrequent_if_exist") => HandleUnknown::Ignore,
+            Some(other) => {
+                return Err(crate::invalid_data(format!(
+                    "Unsupported handle_unknown '{}'",
+                    other
+                )))
+            }
+        };
+        if let Some(drop_idx) = &file.drop_idx {
+            let valid = drop_idx.len() == file.categories.len()
+                && drop_idx
+                    .iter()
+                    .zip(&file.categories)
+                    .all(|(d, c)| d.is_none_or(|d| d < c.len()));
+            if !valid {
+                return Err(crate::invalid_data("Invalid 'drop_idx_'"));
+            }
+        }
+
+        let mut encoder = Self::try_from_categories(file.categories)
+            .map_err(crate::invalid_data)?
+            .with_handle_unknown(handle_unknown);
+        encoder.drop_idx_ = file.drop_idx;
+        Ok(encoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_one_hot_encoder() {
+        let data = arr2(&[["b", "x"], ["a", "y"], ["c", "x"]]);
+        let mut encoder = OneHotEncoder::new();
+        let encoded = encoder.fit_transform(&data);
+
+        assert_eq!(encoder.categories()[0], vec!["a", "b", "c"]);
+        assert_eq!(
+            encoded,
+            arr2(&[
+                [0.0, 1.0, 0.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0, 1.0, 0.0]
+            ])
+        );
+
+        let unknown = arr2(&[["d", "y"]]);
+        assert_eq!(
+            encoder.try_transform(&unknown),
+            Err(ScalerError::UnknownCategory { row: 0, column: 0 })
+        );
+
+        let encoder = OneHotEncoder::from_categories(vec![vec!["a", "b", "c"], vec!["x", "y"]])
+            .with_drop_first(true)
+            .with_handle_unknown(HandleUnknown::Ignore);
+        assert_eq!(encoder.transform(&unknown), arr2(&[[0.0, 0.0, 1.0]]));
+        assert_eq!(
+            encoder.transform(&data).row(0),
+            ndarray::aview1(&[1.0, 0.0, 0.0])
+        );
+
+        assert_eq!(
+            OneHotEncoder::try_from_categories(vec![vec!["a"], vec!["x", "x"]]).err(),
+            Some(ScalerError::DuplicateCategory { column: 1 })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "json"))]
+    fn test_from_json_duplicate_category() {
+        let path = std::env::temp_dir().join("robust_scaler_test_onehot_duplicate.json");
+        std::fs::write(&path, r#"{"categories_": [["a", "b", "a"]]}"#).unwrap();
+        let result = OneHotEncoder::<String>::from_json(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            result.err().unwrap().kind(),
+            std::io::ErrorKind::InvalidData
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use alloc::vec::Vec;
+use ndarray::Array2;
+
+use crate::categories::Categories;
+use crate::ScalerError;
+
+/// OrdinalEncoder: maps the categories of each column to codes `0..n_categories`.
+///
+/// Categories are learned per column during `fit` and sorted, like
+/// scikit-learn's `categories='auto'`, so the output can be fed straight into a
+/// `RobustScaler`. Works with any ordered category type, e.g. `String` or `i64`.
+pub struct OrdinalEncoder<T> {
+    unknown_value: Option<f64>,
+    categories_: Categories<T>, // Categories of each column, by code
+}
+
+impl<T: Ord + Clone> Default for OrdinalEncoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> OrdinalEncoder<T> {
+    /// Creates a new unfitted OrdinalEncoder failing on unknown categories.
+    pub fn new() -> Self {
+        Self {
+            unknown_value: None,
+            categories_: Categories::empty(),
+        }
+    }
+
+    /// Builds a fitted encoder from known categories; the code of a category is
+    /// its position in its column's list.
+    ///
+    /// # Panics
+    /// If a column lists the same category twice. See
+    /// [`OrdinalEncoder::try_from_categories`] for a non-panicking version.
+    pub fn from_categories(categories: Vec<Vec<T>>) -> Self {
+        Self::try_from_categories(categories).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds a fitted encoder from known categories, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// The encoder, or `ScalerError::DuplicateCategory` if a column lists the
+    /// same category twice.
+    pub fn try_from_categories(categories: Vec<Vec<T>>) -> Result<Self, ScalerError> {
+        Ok(Self {
+            categories_: Categories::new(categories)?,
+            ..Self::new()
+        })
+    }
+
+    /// Encodes unknown categories as `value` (e.g. `-1.0` or `f64::NAN`) instead
+    /// of failing, like scikit-learn's `handle_unknown='use_encoded_value'`.
+    ///
+    /// `None` (the default) fails with `ScalerError::UnknownCategory`.
+    pub fn with_unknown_value(mut self, value: Option<f64>) -> Self {
+        self.unknown_value = value;
+        self
+    }
+
+    /// Returns the code given to unknown categories, if any.
+    pub fn unknown_value(&self) -> Option<f64> {
+        self.unknown_value
+    }
+
+    /// Learns the categories of each column.
+    ///
+    /// # Arguments
+    /// * `data` - A 2D array where rows are samples and columns are categorical features.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, data: &Array2<T>) -> &Self {
+        self.categories_ = Categories::fit(data);
+        self
+    }
+
+    /// Encodes `data`, panicking on unknown categories unless an unknown value is set.
+    pub fn transform(&self, data: &Array2<T>) -> Array2<f64> {
+        self.try_transform(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Encodes `data`, returning an error instead of panicking.
+    pub fn try_transform(&self, data: &Array2<T>) -> Result<Array2<f64>, ScalerError> {
+        if data.ncols() != self.categories_.len() {
+            return Err(ScalerError::FeatureCountMismatch {
+                expected: self.categories_.len(),
+                found: data.ncols(),
+            });
+        }
+
+        let mut result = Array2::zeros(data.raw_dim());
+        for ((row, column), value) in data.indexed_iter() {
+            result[[row, column]] =
+                match (self.categories_.position(column, value), self.unknown_value) {
+                    (Some(code), _) => code as f64,
+                    (None, Some(unknown)) => unknown,
+                    (None, None) => return Err(ScalerError::UnknownCategory { row, column }),
+                };
+        }
+        Ok(result)
+    }
+
+    /// Maps codes back to their categories.
+    ///
+    /// # Panics
+    /// If a value is not the code of a category of its column.
+    pub fn inverse_transform(&self, data: &Array2<f64>) -> Array2<T> {
+        assert_eq!(data.ncols(), self.categories_.len());
+
+        Array2::from_shape_fn(data.raw_dim(), |(row, column)| {
+            let code = data[[row, column]];
+            let categories = &self.categories_.as_slice()[column];
+            if code < 0.0 || code != (code as usize) as f64 || code as usize >= categories.len() {
+                panic!("Invalid code {} at row {}, column {}", code, row, column);
+            }
+            categories[code as usize].clone()
+        })
+    }
+
+    /// Learns the categories and encodes the data in one step.
+    pub fn fit_transform(&mut self, data: &Array2<T>) -> Array2<f64> {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// Returns the categories of each column, by code.
+    pub fn categories(&self) -> &[Vec<T>] {
+        self.categories_.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_ordinal_encoder() {
+        let data = arr2(&[[3, 10], [1, 20], [2, 10]]);
+        let mut encoder = OrdinalEncoder::new();
+        let encoded = encoder.fit_transform(&data);
+
+        assert_eq!(encoded, arr2(&[[2.0, 0.0], [0.0, 1.0], [1.0, 0.0]]));
+        assert_eq!(encoder.inverse_transform(&encoded), data);
+
+        let unknown = arr2(&[[4, 20]]);
+        assert_eq!(
+            encoder.try_transform(&unknown),
+            Err(ScalerError::UnknownCategory { row: 0, column: 0 })
+        );
+        let encoder = encoder.with_unknown_value(Some(-1.0));
+        assert_eq!(encoder.transform(&unknown), arr2(&[[-1.0, 1.0]]));
+    }
+}
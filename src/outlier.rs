@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, Axis, Zip};
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::{RobustScaler, Transformer};
+
+impl RobustScaler {
+    /// Flags the values lying outside `center ± k · scale` of their feature,
+    /// i.e. the values whose scaled magnitude exceeds `k`.
+    ///
+    /// With the default estimator this is `median ± k · IQR`. NaN is never flagged.
+    ///
+    /// # Arguments
+    /// * `data` - A 2D array with the same number of features as the scaler.
+    /// * `k` - Number of scales away from the center beyond which a value is an outlier.
+    ///
+    /// # Returns
+    /// A boolean `Array2` of the same shape as `data`.
+    pub fn outlier_mask(&self, data: &Array2<f64>, k: f64) -> Array2<bool> {
+        assert_eq!(data.ncols(), self.n_features());
+
+        let mut mask = Array2::from_elem(data.raw_dim(), false);
+        for ((mut flags, values), (&center, &scale)) in mask
+            .columns_mut()
+            .into_iter()
+            .zip(data.columns())
+            .zip(self.center_.iter().zip(&self.scale_))
+        {
+            Zip::from(&mut flags)
+                .and(&values)
+                .for_each(|flag, &x| *flag = (x - center).abs() > k * scale);
+        }
+        mask
+    }
+
+    /// Flags the rows containing at least one outlier (see [`RobustScaler::outlier_mask`]).
+    pub fn outlier_rows(&self, data: &Array2<f64>, k: f64) -> Array1<bool> {
+        self.outlier_mask(data, k)
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().any(|&flag| flag))
+            .collect()
+    }
+}
+
+/// OutlierFilter: drops the rows containing a value beyond `k` robust scales
+/// from its feature's center.
+///
+/// Wraps a `RobustScaler`, so the rows can be cleaned with `transform` and then
+/// scaled with [`OutlierFilter::scaler`] from the same fitted state. Dropped
+/// rows cannot be restored, so `inverse_transform` is the identity.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct OutlierFilter {
+    #[cfg_attr(feature = "json", serde(with = "crate::pipeline::scaler_params"))]
+    scaler: RobustScaler,
+    k: f64,
+}
+
+impl OutlierFilter {
+    /// Creates a new unfitted filter dropping rows beyond `k` scales (e.g. 3.0).
+    ///
+    /// # Panics
+    /// If `k` is not positive.
+    pub fn new(k: f64) -> Self {
+        Self::from_scaler(RobustScaler::new(), k)
+    }
+
+    /// Creates a filter from a scaler, fitted or not (e.g. configured `with_scale_estimator`).
+    ///
+    /// # Panics
+    /// If `k` is not positive.
+    pub fn from_scaler(scaler: RobustScaler, k: f64) -> Self {
+        assert!(k > 0.0, "Invalid outlier threshold {}", k);
+        Self { scaler, k }
+    }
+
+    /// Fits the robust statistics of each feature.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, data: &Array2<f64>) -> &Self {
+        self.scaler.fit(data);
+        self
+    }
+
+    /// Returns the indices of the rows containing at least one outlier.
+    pub fn outlier_indices(&self, data: &Array2<f64>) -> Vec<usize> {
+        self.indices(data, true)
+    }
+
+    /// Returns the indices of the rows kept by `transform`.
+    pub fn inlier_indices(&self, data: &Array2<f64>) -> Vec<usize> {
+        self.indices(data, false)
+    }
+
+    /// Returns `data` without the rows containing an outlier.
+    pub fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        data.select(Axis(0), &self.inlier_indices(data))
+    }
+
+    /// Returns the data unchanged: dropped rows cannot be recovered.
+    pub fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        assert_eq!(data.ncols(), self.scaler.n_features());
+        data.clone()
+    }
+
+    /// Fits the statistics and drops the outlier rows in one step.
+    pub fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// Returns the threshold, in robust scales.
+    pub fn k(&self) -> f64 {
+        self.k
+    }
+
+    /// Returns the fitted scaler, e.g. to scale the rows kept by `transform`.
+    pub fn scaler(&self) -> &RobustScaler {
+        &self.scaler
+    }
+
+    fn indices(&self, data: &Array2<f64>, outliers: bool) -> Vec<usize> {
+        self.scaler
+            .outlier_rows(data, self.k)
+            .iter()
+            .enumerate()
+            .filter(|(_, &is_outlier)| is_outlier == outliers)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl Transformer for OutlierFilter {
+    fn fit(&mut self, data: &Array2<f64>) {
+        OutlierFilter::fit(self, data);
+    }
+
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        OutlierFilter::transform(self, data)
+    }
+
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        OutlierFilter::inverse_transform(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr1, arr2};
+
+    #[test]
+    fn test_outlier_mask() {
+        let scaler = RobustScaler::from_params(vec![0.0, 10.0], vec![1.0, 2.0]).unwrap();
+        let data = arr2(&[[0.5, 10.0], [-4.0, 13.0], [f64::NAN, 20.0]]);
+
+        assert_eq!(
+            scaler.outlier_mask(&data, 1.5),
+            arr2(&[[false, false], [true, false], [false, true]])
+        );
+        assert_eq!(scaler.outlier_rows(&data, 3.0), arr1(&[false, true, true]));
+    }
+
+    #[test]
+    fn test_outlier_filter() {
+        let data = arr2(&[
+            [1.0, 10.0],
+            [2.0, 20.0],
+            [3.0, 30.0],
+            [4.0, 40.0],
+            [100.0, 25.0],
+        ]);
+        let mut filter = OutlierFilter::new(3.0);
+        let cleaned = filter.fit_transform(&data);
+
+        assert_eq!(filter.outlier_indices(&data), vec![4]);
+        assert_eq!(cleaned, data.slice(ndarray::s![..4, ..]));
+        assert_eq!(filter.scaler().transform(&cleaned).nrows(), 4);
+    }
+}
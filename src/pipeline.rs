@@ -0,0 +1,314 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use ndarray::Array2;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+#[cfg(all(feature = "std", feature = "json"))]
+use std::fs::File;
+#[cfg(all(feature = "std", feature = "json"))]
+use std::io::{BufReader, BufWriter};
+#[cfg(all(feature = "std", feature = "json"))]
+use std::path::Path;
+
+use crate::{
+    Binarizer, FeatureUnion, FunctionTransformer, OutlierFilter, RobustScaler, Transformer,
+    Winsorizer,
+};
+
+/// A step of a [`Pipeline`] (or a branch of a [`FeatureUnion`]) that can be
+/// saved along with the pipeline.
+///
+/// In files, a step is tagged with its `type` and its fitted parameters are
+/// stored under `params` (a scaler uses the same schema as
+/// [`RobustScaler::to_json`]). Every transformer of this crate can be saved,
+/// except a `FunctionTransformer` built from closures and a `Custom` step:
+/// saving a pipeline holding one fails.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json", serde(tag = "type", content = "params"))]
+#[allow(clippy::large_enum_variant)] // A pipeline holds a handful of steps
+pub enum PipelineStep {
+    #[cfg_attr(feature = "json", serde(with = "scaler_params"))]
+    RobustScaler(RobustScaler),
+    Winsorizer(Winsorizer),
+    Binarizer(Binarizer),
+    OutlierFilter(OutlierFilter),
+    FunctionTransformer(FunctionTransformer),
+    FeatureUnion(FeatureUnion),
+    Pipeline(Pipeline),
+    /// Any other `Transformer`, which cannot be saved.
+    #[cfg_attr(
+        feature = "json",
+        serde(serialize_with = "custom_step", skip_deserializing)
+    )]
+    Custom(Box<dyn Transformer>),
+}
+
+macro_rules! impl_from_step {
+    ($($step:ident),*) => {
+        $(
+            impl From<$step> for PipelineStep {
+                fn from(step: $step) -> Self {
+                    PipelineStep::$step(step)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_step!(
+    RobustScaler,
+    Winsorizer,
+    Binarizer,
+    OutlierFilter,
+    FunctionTransformer,
+    FeatureUnion,
+    Pipeline
+);
+
+impl PipelineStep {
+    /// Wraps a transformer that is not part of this crate, e.g. for
+    /// [`Pipeline::with_step`]; such a step cannot be saved.
+    pub fn custom<T: Transformer + 'static>(transformer: T) -> Self {
+        PipelineStep::Custom(Box::new(transformer))
+    }
+
+    pub(crate) fn as_transformer(&self) -> &dyn Transformer {
+        match self {
+            PipelineStep::RobustScaler(step) => step,
+            PipelineStep::Winsorizer(step) => step,
+            PipelineStep::Binarizer(step) => step,
+            PipelineStep::OutlierFilter(step) => step,
+            PipelineStep::FunctionTransformer(step) => step,
+            PipelineStep::FeatureUnion(step) => step,
+            PipelineStep::Pipeline(step) => step,
+            PipelineStep::Custom(step) => step.as_ref(),
+        }
+    }
+
+    pub(crate) fn as_transformer_mut(&mut self) -> &mut dyn Transformer {
+        match self {
+            PipelineStep::RobustScaler(step) => step,
+            PipelineStep::Winsorizer(step) => step,
+            PipelineStep::Binarizer(step) => step,
+            PipelineStep::OutlierFilter(step) => step,
+            PipelineStep::FunctionTransformer(step) => step,
+            PipelineStep::FeatureUnion(step) => step,
+            PipelineStep::Pipeline(step) => step,
+            PipelineStep::Custom(step) => step.as_mut(),
+        }
+    }
+}
+
+/// Fails to save a `Custom` step, which has no schema to be loaded back by.
+#[cfg(feature = "json")]
+#[allow(clippy::borrowed_box)] // The signature `serialize_with` expects
+fn custom_step<S: serde::Serializer>(
+    _step: &Box<dyn Transformer>,
+    _serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Err(serde::ser::Error::custom(
+        "A custom pipeline step cannot be saved",
+    ))
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+struct NamedStep {
+    name: String,
+    #[cfg_attr(feature = "json", serde(flatten))]
+    step: PipelineStep,
+}
+
+/// Pipeline: a chain of named steps, each fitted on the output of the previous one.
+///
+/// E.g. a `Winsorizer` capping outliers followed by a `RobustScaler`. The whole
+/// fitted chain is saved and loaded with `to_json` / `from_json`.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct Pipeline {
+    steps: Vec<NamedStep>,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline, which passes data through unchanged.
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Appends a step; it runs on the output of the previous steps.
+    pub fn with_step<T: Into<PipelineStep>>(mut self, name: &str, step: T) -> Self {
+        self.steps.push(NamedStep {
+            name: name.into(),
+            step: step.into(),
+        });
+        self
+    }
+
+    /// Fits every step on the output of the previous ones.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, data: &Array2<f64>) -> &Self {
+        self.fit_transform(data);
+        self
+    }
+
+    /// Runs `data` through every step in order.
+    pub fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        self.steps
+            .iter()
+            .fold(data.clone(), |x, s| s.step.as_transformer().transform(&x))
+    }
+
+    /// Maps the output of the pipeline back through the steps in reverse order.
+    pub fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        self.steps.iter().rev().fold(data.clone(), |x, s| {
+            s.step.as_transformer().inverse_transform(&x)
+        })
+    }
+
+    /// Fits every step and returns the output of the last one on the training data.
+    pub fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        self.steps.iter_mut().fold(data.clone(), |x, s| {
+            s.step.as_transformer_mut().fit_transform(&x)
+        })
+    }
+
+    /// Returns the step with the given name, if any.
+    pub fn step(&self, name: &str) -> Option<&PipelineStep> {
+        self.steps.iter().find(|s| s.name == name).map(|s| &s.step)
+    }
+
+    /// Returns the names of the steps, in order.
+    pub fn step_names(&self) -> Vec<&str> {
+        self.steps.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    /// Loads a fitted pipeline saved with [`Pipeline::to_json`].
+    ///
+    /// # Returns
+    /// `Ok(Pipeline)`, or an `InvalidData` error naming the problem.
+    #[cfg(all(feature = "std", feature = "json"))]
+    pub fn from_json<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let file = File::open(path.as_ref())?;
+        let reader = BufReader::new(file);
+
+        serde_json::from_reader(reader).map_err(crate::invalid_data)
+    }
+
+    /// Saves the fitted pipeline as JSON: a `steps` list of `name`, `type` and `params`.
+    #[cfg(all(feature = "std", feature = "json"))]
+    pub fn to_json<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let file = File::create(path.as_ref())?;
+        let writer = BufWriter::new(file);
+
+        serde_json::to_writer_pretty(writer, self).map_err(crate::invalid_data)
+    }
+}
+
+impl Transformer for Pipeline {
+    fn fit(&mut self, data: &Array2<f64>) {
+        Pipeline::fit(self, data);
+    }
+
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        Pipeline::transform(self, data)
+    }
+
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        Pipeline::inverse_transform(self, data)
+    }
+
+    fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        Pipeline::fit_transform(self, data)
+    }
+}
+
+/// Stores a scaler step in the versioned schema of the scaler JSON files.
+#[cfg(feature = "json")]
+pub(crate) mod scaler_params {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::format::ScalerFile;
+    use crate::RobustScaler;
+
+    pub fn serialize<S: Serializer>(
+        scaler: &RobustScaler,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ScalerFile::from_scaler(scaler).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<RobustScaler, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        ScalerFile::from_value(value)
+            .and_then(ScalerFile::into_scaler)
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_pipeline() {
+        let data = arr2(&[[1.0, 10.0], [2.0, 20.0], [3.0, 30.0], [100.0, 40.0]]);
+        let mut pipeline = Pipeline::new()
+            .with_step("cap", Winsorizer::new(0.0, 0.75))
+            .with_step("scale", RobustScaler::new());
+        let scaled = pipeline.fit_transform(&data);
+
+        assert_eq!(pipeline.step_names(), vec!["cap", "scale"]);
+        assert!(matches!(
+            pipeline.step("cap"),
+            Some(PipelineStep::Winsorizer(_))
+        ));
+        assert_eq!(pipeline.transform(&data), scaled);
+        let capped = pipeline.inverse_transform(&scaled);
+        assert!((capped[[3, 0]] - 27.25).abs() < 1e-12);
+
+        #[cfg(all(feature = "std", feature = "json"))]
+        {
+            let path = std::env::temp_dir().join("robust_scaler_test_pipeline.json");
+            pipeline.to_json(&path).unwrap();
+            let loaded = Pipeline::from_json(&path).unwrap();
+
+            assert_eq!(loaded.step_names(), pipeline.step_names());
+            assert_eq!(loaded.transform(&data), scaled);
+            assert_eq!(
+                loaded.inverse_transform(&scaled),
+                pipeline.inverse_transform(&scaled)
+            );
+
+            let union = FeatureUnion::new()
+                .with(RobustScaler::new())
+                .with(Binarizer::new(2.5));
+            let mut nested = Pipeline::new()
+                .with_step("log", FunctionTransformer::log1p())
+                .with_step("drop", OutlierFilter::new(3.0))
+                .with_step("union", union)
+                .with_step(
+                    "inner",
+                    Pipeline::new().with_step("scale", RobustScaler::new()),
+                );
+            let scaled = nested.fit_transform(&data);
+            nested.to_json(&path).unwrap();
+            let loaded = Pipeline::from_json(&path).unwrap();
+            assert_eq!(loaded.transform(&data), scaled);
+
+            let closure = Pipeline::new().with_step("f", FunctionTransformer::from_fn(|x| x));
+            assert!(closure.to_json(&path).is_err());
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+}
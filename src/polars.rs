@@ -0,0 +1,85 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use ::polars::prelude::{col, lit, Expr};
+
+use crate::{MissingPolicy, RobustScaler};
+
+impl RobustScaler {
+    /// Returns one polars expression per feature, `(col(name) - center) / scale`
+    /// (enabled by the `polars` feature).
+    ///
+    /// The expressions apply the fitted scaler inside a `LazyFrame` query, e.g.
+    /// `frame.with_columns(scaler.to_polars_exprs())`, so polars can optimize
+    /// and stream it. Columns are the feature names (`x0`, `x1`, ... without
+    /// names, as in scikit-learn), and each result replaces its column.
+    ///
+    /// The clip bounds are applied, and `MissingPolicy::Zero` replaces NaN
+    /// and null with 0. `MissingPolicy::Error` cannot fail inside a query:
+    /// NaN and null are kept, as with `MissingPolicy::Propagate`.
+    pub fn to_polars_exprs(&self) -> Vec<Expr> {
+        (0..self.n_features())
+            .map(|i| {
+                let name = match &self.feature_names_ {
+                    Some(names) => names[i].clone(),
+                    None => format!("x{}", i),
+                };
+                let mut expr = (col(name.as_str()) - lit(self.center_[i])) / lit(self.scale_[i]);
+                if self.missing_ == MissingPolicy::Zero {
+                    expr = expr.fill_nan(lit(0.0)).fill_null(lit(0.0));
+                }
+                if let Some((min, max)) = self.clip_ {
+                    expr = expr.clip(lit(min), lit(max));
+                }
+                expr.alias(name)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::polars::prelude::{Column, DataFrame, IntoLazy};
+    use ndarray::arr2;
+
+    #[test]
+    fn test_to_polars_exprs() {
+        let data = arr2(&[[1.0, 10.0], [2.0, 20.0], [3.0, 30.0], [4.0, 1000.0]]);
+        let mut scaler = RobustScaler::new()
+            .with_feature_names(vec!["a", "b"])
+            .with_clip(-2.0, 2.0)
+            .with_missing_policy(MissingPolicy::Zero);
+        scaler.fit(&data);
+
+        let frame = DataFrame::new_infer_height(vec![
+            Column::new("a".into(), [1.0, 2.0, f64::NAN, 4.0]),
+            Column::new("b".into(), [10.0, 20.0, 30.0, 1000.0]),
+            Column::new("id".into(), ["w", "x", "y", "z"]),
+        ])
+        .unwrap();
+        let scaled = frame
+            .lazy()
+            .with_columns(scaler.to_polars_exprs())
+            .collect()
+            .unwrap();
+
+        let expected = scaler.transform(&arr2(&[
+            [1.0, 10.0],
+            [2.0, 20.0],
+            [f64::NAN, 30.0],
+            [4.0, 1000.0],
+        ]));
+        for (j, name) in ["a", "b"].iter().enumerate() {
+            let values: Vec<f64> = scaled
+                .column(name)
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_no_null_iter()
+                .collect();
+            assert_eq!(values, expected.column(j).to_vec());
+        }
+        assert_eq!(scaled.get_column_names(), ["a", "b", "id"]);
+    }
+}
@@ -0,0 +1,185 @@
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+use crate::{RobustScaler, ScalerError};
+
+/// Quantile range used by scikit-learn's `RobustScaler` by default, in percent.
+pub(crate) const DEFAULT_QUANTILE_RANGE: (f64, f64) = (25.0, 75.0);
+
+/// A feature referred to by column index or by name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FeatureKey {
+    /// Column index.
+    Index(usize),
+    /// Feature name, as given to `with_feature_names`.
+    Name(String),
+}
+
+impl From<usize> for FeatureKey {
+    fn from(index: usize) -> Self {
+        FeatureKey::Index(index)
+    }
+}
+
+impl From<&str> for FeatureKey {
+    fn from(name: &str) -> Self {
+        FeatureKey::Name(name.into())
+    }
+}
+
+impl From<String> for FeatureKey {
+    fn from(name: String) -> Self {
+        FeatureKey::Name(name)
+    }
+}
+
+/// Quantile range of a single feature, overriding the global one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct QuantileRangeOverride {
+    pub(crate) feature: FeatureKey,
+    pub(crate) quantile_range: (f64, f64),
+}
+
+/// Returns `true` if `0 <= q_min < q_max <= 100`.
+pub(crate) fn is_valid_quantile_range((q_min, q_max): (f64, f64)) -> bool {
+    0.0 <= q_min && q_min < q_max && q_max <= 100.0
+}
+
+/// Panics unless `0 <= q_min < q_max <= 100`.
+pub(crate) fn check_quantile_range((q_min, q_max): (f64, f64)) {
+    assert!(
+        is_valid_quantile_range((q_min, q_max)),
+        "Invalid quantile range ({}, {})",
+        q_min,
+        q_max
+    );
+}
+
+impl RobustScaler {
+    /// Sets the quantile range, in percent, whose width is the scale of each
+    /// feature. Defaults to `(25.0, 75.0)`, i.e. the IQR.
+    ///
+    /// Only used by `ScaleEstimator::Iqr`.
+    ///
+    /// # Panics
+    /// Unless `0 <= q_min < q_max <= 100`.
+    pub fn with_quantile_range(mut self, q_min: f64, q_max: f64) -> Self {
+        check_quantile_range((q_min, q_max));
+        self.quantile_range_ = (q_min, q_max);
+        self
+    }
+
+    /// Overrides the quantile range of one feature, given by index or name,
+    /// e.g. `with_feature_quantile_range("price", 5.0, 95.0)`.
+    ///
+    /// Names are resolved at fit time against `with_feature_names`; setting the
+    /// same feature twice keeps the last range.
+    ///
+    /// # Panics
+    /// Unless `0 <= q_min < q_max <= 100`.
+    pub fn with_feature_quantile_range<K: Into<FeatureKey>>(
+        mut self,
+        feature: K,
+        q_min: f64,
+        q_max: f64,
+    ) -> Self {
+        check_quantile_range((q_min, q_max));
+        self.quantile_ranges_.push(QuantileRangeOverride {
+            feature: feature.into(),
+            quantile_range: (q_min, q_max),
+        });
+        self
+    }
+
+    /// Returns the global quantile range, in percent.
+    pub fn quantile_range(&self) -> (f64, f64) {
+        self.quantile_range_
+    }
+
+    /// Returns the quantile range, in percent, used for the feature at `index`.
+    pub fn feature_quantile_range(&self, index: usize) -> (f64, f64) {
+        self.named_feature_quantile_range(self.feature_names_.as_deref(), index)
+    }
+
+    /// Returns the quantile range used for the feature at `index` if the
+    /// features were named `names`, e.g. before a fit assigns the names.
+    pub(crate) fn named_feature_quantile_range(
+        &self,
+        names: Option<&[String]>,
+        index: usize,
+    ) -> (f64, f64) {
+        self.quantile_ranges_
+            .iter()
+            .rev()
+            .find(|o| refers_to(&o.feature, names, index))
+            .map_or(self.quantile_range_, |o| o.quantile_range)
+    }
+
+    /// Checks that every per-feature range refers to one of the `n_features`
+    /// features, named `names`, about to be fitted.
+    pub(crate) fn check_quantile_range_features(
+        &self,
+        names: Option<&[String]>,
+        n_features: usize,
+    ) -> Result<(), ScalerError> {
+        for o in &self.quantile_ranges_ {
+            match &o.feature {
+                FeatureKey::Index(index) if *index >= n_features => {
+                    return Err(ScalerError::FeatureIndexOutOfRange {
+                        index: *index,
+                        n_features,
+                    });
+                }
+                FeatureKey::Name(name) if !names.is_some_and(|names| names.contains(name)) => {
+                    return Err(ScalerError::UnknownFeature { name: name.clone() });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether `key` designates the feature at `index` of features named `names`.
+fn refers_to(key: &FeatureKey, names: Option<&[String]>, index: usize) -> bool {
+    match key {
+        FeatureKey::Index(i) => *i == index,
+        FeatureKey::Name(name) => names.is_some_and(|names| names.get(index) == Some(name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array2;
+
+    #[test]
+    fn test_feature_quantile_ranges() {
+        let data = Array2::from_shape_fn((101, 3), |(i, _)| i as f64);
+        let mut scaler = RobustScaler::new()
+            .with_feature_names(vec!["a", "b", "c"])
+            .with_quantile_range(10.0, 90.0)
+            .with_feature_quantile_range(0, 5.0, 95.0)
+            .with_feature_quantile_range("c", 25.0, 75.0);
+        scaler.fit(&data);
+
+        assert_eq!(scaler.scale(), &[90.0, 80.0, 50.0]);
+        assert_eq!(scaler.feature_quantile_range(2), (25.0, 75.0));
+
+        let mut unknown = RobustScaler::new().with_feature_quantile_range(3, 5.0, 95.0);
+        assert_eq!(
+            unknown.try_fit(&data).err(),
+            Some(ScalerError::FeatureIndexOutOfRange {
+                index: 3,
+                n_features: 3
+            })
+        );
+        let mut unknown = RobustScaler::new().with_feature_quantile_range("d", 5.0, 95.0);
+        assert_eq!(
+            unknown.try_fit(&data).err(),
+            Some(ScalerError::UnknownFeature { name: "d".into() })
+        );
+        assert!(unknown.statistics().is_none());
+    }
+}
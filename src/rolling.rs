@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+use ndarray::{Array1, Array2, ArrayView1, ArrayViewMut1};
+
+use crate::{median_sorted, quantile_sorted};
+
+/// RollingRobustScaler: leakage-free robust scaling for time series.
+///
+/// Each point is scaled by the median and IQR of the points *preceding* it
+/// (a sliding window, or all previous points in expanding mode), so no value
+/// is ever scaled with statistics that include itself or the future. Points
+/// with fewer than `min_periods` preceding values become NaN.
+///
+/// NaN values are missing: they become NaN and are left out of the statistics
+/// of later points, but still occupy their place in the window (so a window of
+/// 3 after a NaN holds 2 values).
+///
+/// The window is kept as a sorted buffer updated by binary-search insertion
+/// and removal, so each step costs a shift of the buffer instead of a re-sort.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollingRobustScaler {
+    window: Option<usize>, // None for expanding mode
+    min_periods: usize,
+}
+
+impl RollingRobustScaler {
+    /// Creates a scaler using the `window` preceding points of each point.
+    ///
+    /// `min_periods` defaults to `window`.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "Window size must be positive");
+        Self {
+            window: Some(window),
+            min_periods: window,
+        }
+    }
+
+    /// Creates a scaler using all preceding points of each point.
+    ///
+    /// `min_periods` defaults to 1.
+    pub fn expanding() -> Self {
+        Self {
+            window: None,
+            min_periods: 1,
+        }
+    }
+
+    /// Sets the minimum number of preceding points needed to scale a point.
+    pub fn with_min_periods(mut self, min_periods: usize) -> Self {
+        assert!(min_periods > 0, "min_periods must be positive");
+        if let Some(window) = self.window {
+            assert!(
+                min_periods <= window,
+                "min_periods cannot exceed the window size"
+            );
+        }
+        self.min_periods = min_periods;
+        self
+    }
+
+    /// Scales a single time series.
+    pub fn transform_series(&self, series: &Array1<f64>) -> Array1<f64> {
+        let mut result = Array1::zeros(series.len());
+        self.scale_column(series.view(), result.view_mut());
+        result
+    }
+
+    /// Scales every column of a 2D time series independently (rows are time steps).
+    pub fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        let mut result = Array2::zeros(data.raw_dim());
+        for (column, out) in data.columns().into_iter().zip(result.columns_mut()) {
+            self.scale_column(column, out);
+        }
+        result
+    }
+
+    fn scale_column(&self, column: ArrayView1<f64>, mut out: ArrayViewMut1<f64>) {
+        let mut window: Vec<f64> = Vec::with_capacity(self.window.unwrap_or(column.len()));
+
+        for (t, &x) in column.iter().enumerate() {
+            out[t] = if window.len() >= self.min_periods {
+                let center = median_sorted(&window);
+                let iqr =
+                    (quantile_sorted(&window, 0.75) - quantile_sorted(&window, 0.25)).max(1e-8);
+                (x - center) / iqr
+            } else {
+                f64::NAN
+            };
+
+            // NaN has no place in the sorted buffer, so it is never inserted
+            if let Some(size) = self.window {
+                if t >= size && !column[t - size].is_nan() {
+                    let old = column[t - size];
+                    let pos = window.partition_point(|&v| v < old);
+                    window.remove(pos);
+                }
+            }
+            if !x.is_nan() {
+                let pos = window.partition_point(|&v| v < x);
+                window.insert(pos, x);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr1;
+
+    #[test]
+    fn test_rolling_scaler() {
+        let series = arr1(&[1.0, 2.0, 3.0, 4.0, 10.0, 6.0]);
+
+        // Point 3 uses [1, 2, 3]: median 2, IQR 1. Point 5 uses [3, 4, 10]: median 4, IQR 3.5.
+        let scaled = RollingRobustScaler::new(3).transform_series(&series);
+        assert!(scaled.iter().take(3).all(|x| x.is_nan()));
+        assert_eq!(scaled[3], 2.0);
+        assert!((scaled[5] - 2.0 / 3.5).abs() < 1e-12);
+
+        // Point 1 has a single preceding value. Point 2 uses [1, 2]: median 1.5, IQR 0.5.
+        let expanding = RollingRobustScaler::expanding().with_min_periods(2);
+        let scaled = expanding.transform_series(&series);
+        assert!(scaled[1].is_nan());
+        assert_eq!(scaled[2], (3.0 - 1.5) / 0.5);
+
+        // The NaN enters the window at point 3 and has left it at point 6
+        let series = arr1(&[1.0, 2.0, f64::NAN, 3.0, 5.0, 6.0, 0.0]);
+        let scaled = RollingRobustScaler::new(3)
+            .with_min_periods(2)
+            .transform_series(&series);
+        assert!(scaled[2].is_nan());
+        assert_eq!(scaled[3], 3.0); // [1, 2]: median 1.5, IQR 0.5
+        assert_eq!(scaled[5], 2.0); // [3, 5]: median 4, IQR 1
+        assert!((scaled[6] + 5.0 / 1.5).abs() < 1e-12); // [3, 5, 6]: median 5, IQR 1.5
+    }
+}
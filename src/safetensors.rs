@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use ::safetensors::tensor::{Dtype, SafeTensors, TensorView};
+use serde_json::Value;
+
+use crate::format::ScalerFile;
+use crate::{RobustScaler, ScalerError};
+
+/// Names of the tensors holding the fitted parameters.
+const TENSORS: [&str; 2] = ["center_", "scale_"];
+
+impl RobustScaler {
+    /// Loads a scaler from a safetensors file (enabled by the `safetensors` feature).
+    ///
+    /// # Arguments
+    /// * `path` - Path to a file written by [`RobustScaler::to_safetensors`].
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)` if successful, `Err` otherwise.
+    pub fn from_safetensors<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Ok(Self::from_safetensors_bytes(&bytes)?)
+    }
+
+    /// Loads a scaler from safetensors bytes written by [`RobustScaler::to_safetensors_bytes`].
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, or `ScalerError::InvalidFile` if the bytes are not a valid scaler.
+    pub fn from_safetensors_bytes(bytes: &[u8]) -> Result<Self, ScalerError> {
+        let invalid = |message: String| ScalerError::InvalidFile { message };
+        let tensors = SafeTensors::deserialize(bytes).map_err(|e| invalid(e.to_string()))?;
+        let (_, header) = SafeTensors::read_metadata(bytes).map_err(|e| invalid(e.to_string()))?;
+
+        // Rebuilds the JSON document of `to_json`, to reuse its version migration
+        let mut document = serde_json::Map::new();
+        for (key, text) in header.metadata().iter().flatten() {
+            let value: Value = serde_json::from_str(text)
+                .map_err(|e| invalid(format!("metadata '{}': {}", key, e)))?;
+            document.insert(key.clone(), value);
+        }
+        for name in TENSORS {
+            let tensor = tensors.tensor(name).map_err(|e| invalid(e.to_string()))?;
+            if tensor.dtype() != Dtype::F64 || tensor.shape().len() != 1 {
+                return Err(invalid(format!(
+                    "'{}' must be a 1D F64 tensor, got {:?} {:?}",
+                    name,
+                    tensor.dtype(),
+                    tensor.shape()
+                )));
+            }
+            let values: Vec<f64> = tensor
+                .data()
+                .chunks_exact(8)
+                .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+                .collect();
+            document.insert(name.into(), values.into());
+        }
+
+        ScalerFile::from_value(Value::Object(document))?.into_scaler()
+    }
+
+    /// Saves the scaler to a safetensors file.
+    ///
+    /// See [`RobustScaler::to_safetensors_bytes`] for the layout.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the safetensors file to create.
+    pub fn to_safetensors<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_safetensors_bytes())
+    }
+
+    /// Serializes the scaler to safetensors.
+    ///
+    /// `center_` and `scale_` are stored as 1D `F64` tensors. Every other field
+    /// of the JSON schema of [`RobustScaler::to_json`] (`format_version`,
+    /// `options` with the quantile range and transform settings, feature names,
+    /// ...) is stored as a JSON string in the header metadata.
+    pub fn to_safetensors_bytes(&self) -> Vec<u8> {
+        let Value::Object(document) = serde_json::to_value(ScalerFile::from_scaler(self))
+            .expect("Serializing to memory cannot fail")
+        else {
+            unreachable!("A scaler serializes to a JSON object")
+        };
+
+        let data: Vec<Vec<u8>> = [&self.center_, &self.scale_]
+            .iter()
+            .map(|values| values.iter().flat_map(|x| x.to_le_bytes()).collect())
+            .collect();
+        let views = TENSORS.iter().zip(&data).map(|(name, bytes)| {
+            let view = TensorView::new(Dtype::F64, vec![bytes.len() / 8], bytes)
+                .expect("The shape matches the data");
+            (*name, view)
+        });
+        let metadata: HashMap<String, String> = document
+            .into_iter()
+            .filter(|(key, _)| !TENSORS.contains(&key.as_str()))
+            .map(|(key, value)| (key, value.to_string()))
+            .collect();
+
+        ::safetensors::serialize(views, Some(metadata)).expect("Serializing to memory cannot fail")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_safetensors_round_trip() {
+        let mut scaler = RobustScaler::new()
+            .with_quantile_range(10.0, 90.0)
+            .with_clip(-3.0, 3.0)
+            .with_feature_names(vec!["a", "b"]);
+        scaler.fit(&arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]]));
+
+        let path = std::env::temp_dir().join("robust_scaler_test_safetensors.safetensors");
+        scaler.to_safetensors(&path).unwrap();
+        let loaded = RobustScaler::from_safetensors(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.center(), scaler.center());
+        assert_eq!(loaded.scale(), scaler.scale());
+        assert_eq!(loaded.clip(), scaler.clip());
+        assert_eq!(loaded.quantile_range(), (10.0, 90.0));
+        assert_eq!(loaded.feature_names(), scaler.feature_names());
+
+        assert!(RobustScaler::from_safetensors_bytes(b"not safetensors").is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+
+/// SplitMix64: a small, fast and seedable generator, good enough for sampling.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniform integer in `0..n` (Lemire's multiply-shift).
+    fn below(&mut self, n: usize) -> usize {
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+}
+
+/// Keeps a uniform random subset of at most `max_samples` values, without replacement.
+///
+/// The chosen positions only depend on `values.len()` and `seed`, so columns of
+/// the same matrix sampled with the same seed keep the same rows.
+pub(crate) fn subsample(mut values: Vec<f64>, max_samples: usize, seed: u64) -> Vec<f64> {
+    if values.len() <= max_samples {
+        return values;
+    }
+
+    // Partial Fisher-Yates shuffle of the first `max_samples` positions
+    let mut rng = SplitMix64::new(seed);
+    for i in 0..max_samples {
+        let j = i + rng.below(values.len() - i);
+        values.swap(i, j);
+    }
+    values.truncate(max_samples);
+    values
+}
+
+/// Keeps a uniform random subset of at most `max_samples` of the values pushed
+/// so far (Algorithm R), for fitting on streams of unknown length.
+///
+/// Like [`subsample`], reservoirs with the same seed fed the same number of
+/// values keep the same positions.
+#[cfg(feature = "postgres")]
+pub(crate) struct Reservoir {
+    values: Vec<f64>,
+    seen: usize,
+    max_samples: usize,
+    rng: SplitMix64,
+}
+
+#[cfg(feature = "postgres")]
+impl Reservoir {
+    /// Creates a reservoir for the `(max_samples, seed)` of `with_subsample`;
+    /// `None` keeps every value.
+    pub(crate) fn new(subsample: Option<(usize, u64)>) -> Self {
+        let (max_samples, seed) = subsample.unwrap_or((usize::MAX, 0));
+        Self {
+            values: Vec::new(),
+            seen: 0,
+            max_samples,
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    pub(crate) fn push(&mut self, x: f64) {
+        self.seen += 1;
+        if self.values.len() < self.max_samples {
+            self.values.push(x);
+        } else {
+            let j = self.rng.below(self.seen);
+            if j < self.max_samples {
+                self.values[j] = x;
+            }
+        }
+    }
+
+    pub(crate) fn into_values(self) -> Vec<f64> {
+        self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsample() {
+        let values: Vec<f64> = (0..1000).map(f64::from).collect();
+        let sample = subsample(values.clone(), 100, 42);
+        assert_eq!(sample.len(), 100);
+        assert_eq!(sample, subsample(values.clone(), 100, 42));
+        assert_ne!(sample, subsample(values.clone(), 100, 7));
+
+        let mut unique = sample.clone();
+        unique.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        unique.dedup();
+        assert_eq!(unique.len(), 100);
+
+        assert_eq!(subsample(values.clone(), 5000, 42), values);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_reservoir() {
+        let mut reservoir = Reservoir::new(Some((100, 42)));
+        (0..1000).for_each(|i| reservoir.push(f64::from(i)));
+        let sample = reservoir.into_values();
+        assert_eq!(sample.len(), 100);
+        assert!(sample.iter().any(|&x| x >= 100.0));
+
+        let mut all = Reservoir::new(None);
+        (0..10).for_each(|i| all.push(f64::from(i)));
+        assert_eq!(all.into_values().len(), 10);
+    }
+}
@@ -0,0 +1,57 @@
+use ndarray::{Array1, ArrayBase, Axis, Data, Ix1};
+
+use crate::RobustScaler;
+
+impl RobustScaler {
+    /// Fits a single-feature scaler to a series, e.g. the target of a regression.
+    ///
+    /// Avoids reshaping `y` to a one-column `Array2`; pair with
+    /// [`RobustScaler::transform_series`] before training and
+    /// [`RobustScaler::inverse_series`] on the predictions.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    ///
+    /// # Panics
+    /// If the series is empty.
+    pub fn fit_1d<S: Data<Elem = f64>>(&mut self, series: &ArrayBase<S, Ix1>) -> &Self {
+        self.fit(&series.view().insert_axis(Axis(1)))
+    }
+
+    /// Scales a series with a single-feature scaler.
+    ///
+    /// # Panics
+    /// If the scaler does not have exactly one feature, or as [`RobustScaler::transform`].
+    pub fn transform_series<S: Data<Elem = f64>>(&self, series: &ArrayBase<S, Ix1>) -> Array1<f64> {
+        self.transform(&series.view().insert_axis(Axis(1)))
+            .index_axis_move(Axis(1), 0)
+    }
+
+    /// Maps a scaled series (e.g. predictions) back to the original units.
+    ///
+    /// # Panics
+    /// If the scaler does not have exactly one feature.
+    pub fn inverse_series<S: Data<Elem = f64>>(&self, series: &ArrayBase<S, Ix1>) -> Array1<f64> {
+        self.inverse_transform(&series.view().insert_axis(Axis(1)))
+            .index_axis_move(Axis(1), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr1;
+
+    #[test]
+    fn test_series() {
+        let y = arr1(&[10.0, 20.0, 30.0, 40.0, 1000.0]);
+        let mut scaler = RobustScaler::new();
+        scaler.fit_1d(&y);
+        assert_eq!(scaler.center(), &[30.0]);
+        assert_eq!(scaler.scale(), &[20.0]);
+
+        let scaled = scaler.transform_series(&y);
+        assert_eq!(scaled, arr1(&[-1.0, -0.5, 0.0, 0.5, 48.5]));
+        assert_eq!(scaler.inverse_series(&scaled), y);
+    }
+}
@@ -0,0 +1,112 @@
+//! A minimal HTTP server exposing a scaler, for quick deployments.
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! robust_scaler::serve::serve("robust_scaler.json", "0.0.0.0:8080").await
+//! # }
+//! ```
+
+use std::path::Path;
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{json, Value};
+use tokio::net::{TcpListener, ToSocketAddrs};
+
+use crate::RobustScaler;
+
+/// Builds an HTTP router around a fitted scaler.
+///
+/// Routes:
+/// - `POST /transform`: takes a JSON array of rows, e.g. `[[1.0, 2.0], [3.0, 4.0]]`,
+///   and returns the scaled rows; invalid rows are rejected with
+///   `400 Bad Request` and `{"error": "..."}`.
+/// - `GET /health`: returns `{"status": "ok", "n_features": ...}`.
+pub fn router(scaler: RobustScaler) -> Router {
+    Router::new()
+        .route("/transform", post(transform))
+        .route("/health", get(health))
+        .with_state(Arc::new(scaler))
+}
+
+/// Loads a scaler from a JSON file and serves [`router`] on `addr` until the
+/// process is stopped.
+///
+/// # Returns
+/// `Err` if the scaler cannot be loaded or the address cannot be bound.
+pub async fn serve<P, A>(path: P, addr: A) -> std::io::Result<()>
+where
+    P: AsRef<Path>,
+    A: ToSocketAddrs,
+{
+    let scaler = RobustScaler::from_json(path)?;
+    let listener = TcpListener::bind(addr).await?;
+    axum::serve(listener, router(scaler)).await
+}
+
+async fn transform(
+    State(scaler): State<Arc<RobustScaler>>,
+    Json(rows): Json<Vec<Vec<f64>>>,
+) -> Result<Json<Vec<Vec<f64>>>, (StatusCode, Json<Value>)> {
+    scaler.try_transform_rows(&rows).map(Json).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": e.to_string() })),
+        )
+    })
+}
+
+async fn health(State(scaler): State<Arc<RobustScaler>>) -> Json<Value> {
+    Json(json!({ "status": "ok", "n_features": scaler.n_features() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    /// Sends a raw HTTP/1.1 request and returns the response.
+    fn request(addr: std::net::SocketAddr, method: &str, path: &str, body: &str) -> String {
+        let mut stream = std::net::TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+             Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_serve() {
+        let scaler = RobustScaler::from_params(vec![1.0, 2.0], vec![2.0, 4.0]).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router(scaler)).await });
+
+        let (health, transformed, invalid) = tokio::task::spawn_blocking(move || {
+            (
+                request(addr, "GET", "/health", ""),
+                request(addr, "POST", "/transform", "[[3.0, 6.0], [1.0, 2.0]]"),
+                request(addr, "POST", "/transform", "[[3.0]]"),
+            )
+        })
+        .await
+        .unwrap();
+
+        assert!(health.starts_with("HTTP/1.1 200"));
+        assert!(health.ends_with(r#"{"n_features":2,"status":"ok"}"#));
+        assert!(transformed.ends_with("[[1.0,1.0],[0.0,0.0]]"));
+        assert!(invalid.starts_with("HTTP/1.1 400"));
+        assert!(invalid.contains("Row 0 has 1 values"));
+    }
+}
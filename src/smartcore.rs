@@ -0,0 +1,132 @@
+use ::smartcore::api::{Transformer, UnsupervisedEstimator};
+use ::smartcore::error::Failed;
+use ::smartcore::linalg::basic::arrays::{Array, Array2 as Matrix};
+use ::smartcore::linalg::basic::matrix::DenseMatrix;
+use ndarray::Array2;
+
+use crate::range::DEFAULT_QUANTILE_RANGE;
+use crate::RobustScaler;
+
+/// Parameters used when a `RobustScaler` is fitted through smartcore's
+/// `UnsupervisedEstimator` trait.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RobustScalerParameters {
+    /// Percentiles (0-100) whose distance is used as the scale.
+    pub quantile_range: (f64, f64),
+}
+
+impl Default for RobustScalerParameters {
+    fn default() -> Self {
+        Self {
+            quantile_range: DEFAULT_QUANTILE_RANGE,
+        }
+    }
+}
+
+impl RobustScalerParameters {
+    /// Sets the quantile range, as in [`RobustScaler::with_quantile_range`].
+    pub fn with_quantile_range(mut self, q_min: f64, q_max: f64) -> Self {
+        self.quantile_range = (q_min, q_max);
+        self
+    }
+}
+
+impl RobustScaler {
+    /// Fits the scaler to a smartcore `DenseMatrix`, rows are samples.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    ///
+    /// # Panics
+    /// Like [`RobustScaler::fit`].
+    pub fn fit_dense(&mut self, data: &DenseMatrix<f64>) -> &Self {
+        self.fit(&to_ndarray(data))
+    }
+
+    /// Transforms a smartcore `DenseMatrix` using the fitted center and scale.
+    ///
+    /// # Returns
+    /// A new row-major `DenseMatrix`.
+    ///
+    /// # Panics
+    /// Like [`RobustScaler::transform`].
+    pub fn transform_dense(&self, data: &DenseMatrix<f64>) -> DenseMatrix<f64> {
+        from_ndarray(&self.transform(&to_ndarray(data)))
+    }
+
+    /// Reverts the scaling of a smartcore `DenseMatrix`.
+    pub fn inverse_transform_dense(&self, data: &DenseMatrix<f64>) -> DenseMatrix<f64> {
+        from_ndarray(&self.inverse_transform(&to_ndarray(data)))
+    }
+}
+
+/// Lets a `RobustScaler` be fitted like smartcore's own preprocessors:
+/// `RobustScaler::fit(&x, RobustScalerParameters::default())`.
+impl<M: Matrix<f64>> UnsupervisedEstimator<M, RobustScalerParameters> for RobustScaler {
+    fn fit(x: &M, parameters: RobustScalerParameters) -> Result<Self, Failed> {
+        let (q_min, q_max) = parameters.quantile_range;
+        if !crate::range::is_valid_quantile_range((q_min, q_max)) {
+            return Err(Failed::fit(&format!(
+                "Invalid quantile range: ({}, {})",
+                q_min, q_max
+            )));
+        }
+
+        let mut scaler = RobustScaler::new().with_quantile_range(q_min, q_max);
+        scaler
+            .try_fit(&to_ndarray(x))
+            .map_err(|e| Failed::fit(&e.to_string()))?;
+        Ok(scaler)
+    }
+}
+
+impl<M: Matrix<f64>> Transformer<M> for RobustScaler {
+    fn transform(&self, x: &M) -> Result<M, Failed> {
+        let result = self
+            .try_transform(&to_ndarray(x))
+            .map_err(|e| Failed::transform(&e.to_string()))?;
+        let (nrows, ncols) = result.dim();
+        Ok(M::from_iterator(result.into_iter(), nrows, ncols, 0))
+    }
+}
+
+fn to_ndarray<M: Array<f64, (usize, usize)>>(data: &M) -> Array2<f64> {
+    Array2::from_shape_vec(data.shape(), data.iterator(0).copied().collect())
+        .expect("Iterator yields nrows * ncols values")
+}
+
+fn from_ndarray(data: &Array2<f64>) -> DenseMatrix<f64> {
+    let (nrows, ncols) = data.dim();
+    DenseMatrix::from_iterator(data.iter().copied(), nrows, ncols, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dense_matrix() {
+        let x = DenseMatrix::from_2d_array(&[&[1.0, 10.0], &[2.0, 20.0], &[3.0, 30.0]]).unwrap();
+
+        let mut scaler = RobustScaler::new();
+        let scaled = scaler.fit_dense(&x).transform_dense(&x);
+        assert_eq!(*scaled.get((2, 1)), 1.0);
+        assert_eq!(
+            scaler
+                .inverse_transform_dense(&scaled)
+                .iterator(0)
+                .collect::<Vec<_>>(),
+            x.iterator(0).collect::<Vec<_>>()
+        );
+
+        let fitted: RobustScaler =
+            UnsupervisedEstimator::fit(&x, RobustScalerParameters::default()).unwrap();
+        assert_eq!(fitted.center(), &[2.0, 20.0]);
+        let transformed = Transformer::transform(&fitted, &x).unwrap();
+        assert_eq!(*transformed.get((0, 0)), -1.0);
+        assert!(
+            Transformer::transform(&fitted, &DenseMatrix::from_2d_array(&[&[1.0]]).unwrap())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{median_sorted, quantile_sorted};
+
+/// Summary of what the scaler learned about a single feature during `fit`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureStatistics {
+    /// Number of samples the statistics were computed from: after subsampling,
+    /// when fitted with `with_subsample`.
+    pub count: usize,
+    /// Smallest value seen.
+    pub min: f64,
+    /// Largest value seen.
+    pub max: f64,
+    /// 25th percentile.
+    pub q1: f64,
+    /// Median.
+    pub median: f64,
+    /// 75th percentile.
+    pub q3: f64,
+}
+
+impl FeatureStatistics {
+    /// Computes the statistics of an already sorted, non-empty column.
+    pub(crate) fn from_sorted(sorted: &[f64]) -> Self {
+        Self {
+            count: sorted.len(),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            q1: quantile_sorted(sorted, 0.25),
+            median: median_sorted(sorted),
+            q3: quantile_sorted(sorted, 0.75),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RobustScaler;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_statistics_after_fit() {
+        let mut scaler = RobustScaler::new();
+        assert!(scaler.statistics().is_none());
+
+        scaler.fit(&arr2(&[[5.0, 2.0], [1.0, 4.0], [3.0, 6.0], [7.0, 8.0]]));
+        let stats = &scaler.statistics().unwrap()[0];
+        assert_eq!(
+            stats,
+            &FeatureStatistics {
+                count: 4,
+                min: 1.0,
+                max: 7.0,
+                q1: 2.5,
+                median: 4.0,
+                q3: 5.5,
+            }
+        );
+        assert_eq!(scaler.center()[0], stats.median);
+        assert_eq!(scaler.scale()[0], stats.q3 - stats.q1);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_save_statistics() {
+        use crate::format::ScalerFile;
+        let saved = |scaler: &RobustScaler| {
+            serde_json::to_string(&ScalerFile::from_scaler(scaler)).unwrap()
+        };
+        let data = arr2(&[[1.0], [2.0], [3.0], [4.0], [5.0], [6.0]]);
+        let mut scaler = RobustScaler::new().with_subsample(4, 7);
+        scaler.fit(&data);
+        assert_eq!(scaler.statistics().unwrap()[0].count, 4);
+        assert!(saved(&scaler).contains("\"statistics\""));
+
+        let mut scaler = RobustScaler::new().with_save_statistics(false);
+        scaler.fit(&data);
+        assert!(scaler.statistics().is_some());
+        let json = saved(&scaler);
+        assert!(!json.contains("\"statistics\""));
+
+        // The setting is saved too, so a refit of the loaded scaler is not saved
+        // with statistics either
+        let mut loaded = RobustScaler::from_json_str(&json).unwrap();
+        loaded.fit(&data);
+        assert!(!saved(&loaded).contains("\"statistics\""));
+    }
+}
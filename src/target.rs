@@ -0,0 +1,241 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use ndarray::{Array2, ArrayView1};
+
+/// TargetEncoder: replaces each category by a smoothed mean of the target.
+///
+/// The encoding of a category seen `n` times with target mean `mean` is
+/// `(n * mean + m * global_mean) / (n + m)`, where `m` is the smoothing, so rare
+/// categories shrink towards the global mean. Unknown categories are encoded
+/// as the global mean.
+///
+/// Like scikit-learn's `TargetEncoder`, `fit_transform` cross-fits: each row is
+/// encoded with statistics learned on the other folds, so the training output
+/// does not leak the row's own target.
+pub struct TargetEncoder<T> {
+    smoothing: f64,
+    cv: usize,
+    encodings_: Vec<BTreeMap<T, f64>>, // Encoding of each category, per column
+    target_mean_: f64,                 // Global target mean, used for unknown categories
+}
+
+impl<T: Ord + Clone> Default for TargetEncoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> TargetEncoder<T> {
+    /// Creates a new unfitted TargetEncoder with a smoothing of 1 and 5 folds.
+    pub fn new() -> Self {
+        Self {
+            smoothing: 1.0,
+            cv: 5,
+            encodings_: vec![],
+            target_mean_: 0.0,
+        }
+    }
+
+    /// Builds a fitted encoder from known encodings (e.g. scikit-learn's
+    /// `categories_` zipped with `encodings_`) and the global target mean.
+    pub fn from_encodings(encodings: Vec<BTreeMap<T, f64>>, target_mean: f64) -> Self {
+        Self {
+            encodings_: encodings,
+            target_mean_: target_mean,
+            ..Self::new()
+        }
+    }
+
+    /// Sets the smoothing `m`, in number of samples. Defaults to 1.
+    ///
+    /// # Panics
+    /// If `smoothing` is negative or NaN.
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        assert!(smoothing >= 0.0, "Invalid smoothing {}", smoothing);
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Sets the number of cross-fitting folds used by `fit_transform`. Defaults to 5.
+    ///
+    /// Rows are assigned to folds in turn (row `i` goes to fold `i % cv`); shuffle
+    /// the data beforehand if it is sorted.
+    ///
+    /// # Panics
+    /// If `cv` is less than 2.
+    pub fn with_cv(mut self, cv: usize) -> Self {
+        assert!(cv >= 2, "At least 2 folds are needed, got {}", cv);
+        self.cv = cv;
+        self
+    }
+
+    /// Learns the encoding of every category on all rows.
+    ///
+    /// # Arguments
+    /// * `data` - A 2D array where rows are samples and columns are categorical features.
+    /// * `target` - Target value of each row.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, data: &Array2<T>, target: &[f64]) -> &Self {
+        assert_eq!(
+            data.nrows(),
+            target.len(),
+            "Target length does not match the data"
+        );
+
+        let rows: Vec<usize> = (0..data.nrows()).collect();
+        self.target_mean_ = mean(target, &rows);
+        self.encodings_ = data
+            .columns()
+            .into_iter()
+            .map(|column| self.encode_column(column, target, &rows, self.target_mean_))
+            .collect();
+        self
+    }
+
+    /// Encodes `data` with the encodings learned by `fit`.
+    pub fn transform(&self, data: &Array2<T>) -> Array2<f64> {
+        assert_eq!(data.ncols(), self.encodings_.len());
+
+        Array2::from_shape_fn(data.raw_dim(), |(row, column)| {
+            self.encodings_[column]
+                .get(&data[[row, column]])
+                .copied()
+                .unwrap_or(self.target_mean_)
+        })
+    }
+
+    /// Fits on all rows and returns the cross-fitted encoding of the training data.
+    pub fn fit_transform(&mut self, data: &Array2<T>, target: &[f64]) -> Array2<f64> {
+        self.fit(data, target);
+
+        let mut result = Array2::zeros(data.raw_dim());
+        for fold in 0..self.cv {
+            let (held_out, train): (Vec<usize>, Vec<usize>) =
+                (0..data.nrows()).partition(|i| i % self.cv == fold);
+            let fold_mean = mean(target, &train);
+
+            for (j, column) in data.columns().into_iter().enumerate() {
+                let encodings = self.encode_column(column, target, &train, fold_mean);
+                for &i in &held_out {
+                    result[[i, j]] = encodings.get(&column[i]).copied().unwrap_or(fold_mean);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the encoding of each category, per column.
+    pub fn encodings(&self) -> &[BTreeMap<T, f64>] {
+        &self.encodings_
+    }
+
+    /// Returns the global target mean.
+    pub fn target_mean(&self) -> f64 {
+        self.target_mean_
+    }
+
+    /// Computes the smoothed encodings of one column over the given rows.
+    fn encode_column(
+        &self,
+        column: ArrayView1<T>,
+        target: &[f64],
+        rows: &[usize],
+        prior: f64,
+    ) -> BTreeMap<T, f64> {
+        let mut sums: BTreeMap<&T, (f64, usize)> = BTreeMap::new();
+        for &i in rows {
+            let entry = sums.entry(&column[i]).or_insert((0.0, 0));
+            entry.0 += target[i];
+            entry.1 += 1;
+        }
+        sums.into_iter()
+            .map(|(category, (sum, count))| {
+                let encoding = (sum + self.smoothing * prior) / (count as f64 + self.smoothing);
+                (category.clone(), encoding)
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "json"))]
+impl<T: Ord + Clone + serde::de::DeserializeOwned> TargetEncoder<T> {
+    /// Loads an encoder exported from scikit-learn as JSON.
+    ///
+    /// Expects a JSON with:
+    /// - "categories_": list of category lists, one per column
+    /// - "encodings_": list of encoding lists, matching "categories_"
+    /// - "target_mean_": global target mean
+    ///
+    /// # Returns
+    /// `Ok(TargetEncoder)` if successful, `Err` otherwise.
+    pub fn from_json<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        #[derive(serde::Deserialize)]
+        struct TargetFile<T> {
+            #[serde(rename = "categories_")]
+            categories: Vec<Vec<T>>,
+            #[serde(rename = "encodings_")]
+            encodings: Vec<Vec<f64>>,
+            #[serde(rename = "target_mean_")]
+            target_mean: f64,
+        }
+
+        let reader = std::io::BufReader::new(std::fs::File::open(path)?);
+        let file: TargetFile<T> = serde_json::from_reader(reader).map_err(crate::invalid_data)?;
+
+        let lengths_match = file.categories.len() == file.encodings.len()
+            && file
+                .categories
+                .iter()
+                .zip(&file.encodings)
+                .all(|(c, e)| c.len() == e.len());
+        if !lengths_match {
+            return Err(crate::invalid_data(
+                "'encodings_' does not match 'categories_'",
+            ));
+        }
+
+        let encodings = file
+            .categories
+            .into_iter()
+            .zip(file.encodings)
+            .map(|(categories, encodings)| categories.into_iter().zip(encodings).collect())
+            .collect();
+        Ok(Self::from_encodings(encodings, file.target_mean))
+    }
+}
+
+/// Mean of the target over the given rows (0 if there are none).
+fn mean(target: &[f64], rows: &[usize]) -> f64 {
+    if rows.is_empty() {
+        return 0.0;
+    }
+    rows.iter().map(|&i| target[i]).sum::<f64>() / rows.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_target_encoder() {
+        let data = arr2(&[["a"], ["a"], ["b"], ["b"]]);
+        let target = [1.0, 3.0, 10.0, 10.0];
+        let mut encoder = TargetEncoder::new().with_cv(2);
+        let cross_fitted = encoder.fit_transform(&data, &target);
+
+        // a: (4 + 6) / 3, b: (20 + 6) / 3, with a global mean of 6
+        assert_eq!(encoder.target_mean(), 6.0);
+        assert_eq!(encoder.encodings()[0]["a"], 10.0 / 3.0);
+        assert_eq!(
+            encoder.transform(&arr2(&[["b"], ["c"]])),
+            arr2(&[[26.0 / 3.0], [6.0]])
+        );
+
+        // Row 0 is encoded from rows 1 and 3 only: a -> (3 + 6.5) / 2
+        assert_eq!(cross_fitted[[0, 0]], 4.75);
+    }
+}
@@ -0,0 +1,83 @@
+use std::path::Path;
+
+use crate::format::{scaler_from_document, ScalerFile};
+use crate::{RobustScaler, ScalerError};
+
+impl RobustScaler {
+    /// Loads a scaler from a TOML file (enabled by the `toml` feature).
+    ///
+    /// Uses the same schema as [`RobustScaler::from_json`], so a JSON export can
+    /// be converted to TOML with any tool.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the TOML file.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)` if successful, `Err` otherwise.
+    pub fn from_toml<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self::from_toml_str(&text)?)
+    }
+
+    /// Loads a scaler from a TOML string, using the same schema as [`RobustScaler::from_json`].
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, or `ScalerError::InvalidFile` if the string is not a valid scaler.
+    pub fn from_toml_str(text: &str) -> Result<Self, ScalerError> {
+        scaler_from_document(::toml::from_str(text))
+    }
+
+    /// Saves the scaler to a TOML file using the same schema as [`RobustScaler::to_json`].
+    ///
+    /// # Arguments
+    /// * `path` - Path of the TOML file to create.
+    pub fn to_toml<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_toml_string())
+    }
+
+    /// Serializes the scaler to a TOML string.
+    pub fn to_toml_string(&self) -> String {
+        // A scaler file is a table of numbers, strings, arrays and subtables, all
+        // of which TOML can represent
+        ::toml::to_string(&ScalerFile::from_scaler(self))
+            .expect("A scaler file is representable in TOML")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_toml_round_trip() {
+        let mut scaler = RobustScaler::new()
+            .with_clip(-3.0, 3.0)
+            .with_feature_names(vec!["a", "b"])
+            .with_tag("model", "churn");
+        scaler.fit(&arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]]));
+
+        let path = std::env::temp_dir().join("robust_scaler_test_toml_round_trip.toml");
+        scaler.to_toml(&path).unwrap();
+        let loaded = RobustScaler::from_toml(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.center(), scaler.center());
+        assert_eq!(loaded.statistics(), scaler.statistics());
+        assert_eq!(loaded.metadata(), scaler.metadata());
+
+        // A seed above `i64::MAX`, the largest integer in the TOML spec, still
+        // round-trips
+        let seeded = RobustScaler::from_params(vec![0.0], vec![1.0])
+            .unwrap()
+            .with_subsample(10, u64::MAX);
+        let loaded = RobustScaler::from_toml_str(&seeded.to_toml_string()).unwrap();
+        assert_eq!(loaded.subsample_, Some((10, u64::MAX)));
+
+        // TOML forbids defining a key twice
+        assert!(matches!(
+            RobustScaler::from_toml_str("center_ = [1.0]\ncenter_ = [2.0]\nscale_ = [1.0]"),
+            Err(ScalerError::InvalidFile { .. })
+        ));
+    }
+}
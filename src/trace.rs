@@ -0,0 +1,87 @@
+use std::time::Instant;
+
+use tracing::span::EnteredSpan;
+use tracing::Span;
+
+/// An entered span that records its own duration when dropped.
+///
+/// Spans are created with `n_rows`, `n_features` and `duration_us` fields;
+/// fields that are only known after the work (e.g. the number of features of
+/// a loaded scaler) are filled in with [`Timed::record`].
+pub(crate) struct Timed {
+    span: EnteredSpan,
+    start: Instant,
+}
+
+impl Timed {
+    /// Enters `span` and starts the clock.
+    pub(crate) fn new(span: Span) -> Self {
+        Self {
+            span: span.entered(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Sets a field that was declared `Empty` when the span was created.
+    pub(crate) fn record(&self, field: &str, value: usize) {
+        self.span.record(field, value);
+    }
+}
+
+impl Drop for Timed {
+    fn drop(&mut self) {
+        let duration_us = self.start.elapsed().as_micros() as u64;
+        self.span.record("duration_us", duration_us);
+        tracing::debug!(duration_us, "finished");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use ndarray::arr2;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::RobustScaler;
+
+    /// Collects the names of the spans that were created.
+    #[derive(Default)]
+    struct SpanNames(Arc<Mutex<Vec<&'static str>>>);
+
+    impl Subscriber for SpanNames {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut names = self.0.lock().unwrap();
+            names.push(span.metadata().name());
+            Id::from_u64(names.len() as u64)
+        }
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, _event: &Event<'_>) {}
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn test_spans() {
+        let subscriber = SpanNames::default();
+        let names = subscriber.0.clone();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let data = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+            let mut scaler = RobustScaler::new();
+            scaler.fit(&data);
+            scaler.transform(&data);
+            scaler.fit_transform(&data);
+        });
+
+        assert_eq!(
+            *names.lock().unwrap(),
+            vec!["fit", "transform", "fit_transform"]
+        );
+    }
+}
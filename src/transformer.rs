@@ -0,0 +1,22 @@
+use ndarray::Array2;
+
+/// Common interface of the preprocessing steps provided by this crate.
+///
+/// Lets heterogeneous steps (scalers, winsorizers, ...) be chained or stored
+/// behind a `Box<dyn Transformer>`.
+pub trait Transformer {
+    /// Learns the parameters of the step from 2D data (rows are samples).
+    fn fit(&mut self, data: &Array2<f64>);
+
+    /// Applies the fitted step to 2D data.
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64>;
+
+    /// Maps transformed data back to the original space, as far as the step allows.
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64>;
+
+    /// Fits the step and transforms the data in one step.
+    fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        self.fit(data);
+        self.transform(data)
+    }
+}
@@ -0,0 +1,153 @@
+//! A scaler whose fitted state is tracked by the type system.
+//!
+//! `RobustScaler<Unfitted>` can only be fitted, and `fit` returns a
+//! `RobustScaler<Fitted>`, which dereferences to the dynamic
+//! [`crate::RobustScaler`] for `transform` and every other read-only method.
+//! Transforming with an unfitted scaler is then a compile error:
+//!
+//! ```compile_fail
+//! use robust_scaler::typestate::RobustScaler;
+//!
+//! let data = ndarray::arr2(&[[1.0], [2.0], [3.0]]);
+//! let scaler = RobustScaler::new();
+//! scaler.transform(&data); // No `transform` before `fit`
+//! ```
+//!
+//! ```
+//! use robust_scaler::typestate::RobustScaler;
+//!
+//! let data = ndarray::arr2(&[[1.0], [2.0], [3.0]]);
+//! let scaler = RobustScaler::new().fit(&data);
+//! assert_eq!(scaler.transform(&data), ndarray::arr2(&[[-1.0], [0.0], [1.0]]));
+//! ```
+//!
+//! Scalers loaded from files (or built with the dynamic API) are checked once
+//! when converted with `RobustScaler::<Fitted>::try_from`.
+
+use core::marker::PhantomData;
+use core::ops::Deref;
+use ndarray::{ArrayBase, Data, Ix2};
+
+use crate::ScalerError;
+
+/// Marker of a scaler that has not been fitted yet.
+pub struct Unfitted;
+
+/// Marker of a scaler with fitted parameters.
+pub struct Fitted;
+
+/// A [`crate::RobustScaler`] in the `Unfitted` or `Fitted` state.
+pub struct RobustScaler<State> {
+    inner: crate::RobustScaler,
+    state: PhantomData<State>,
+}
+
+impl Default for RobustScaler<Unfitted> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RobustScaler<Unfitted> {
+    /// Creates an unfitted scaler with the default settings.
+    pub fn new() -> Self {
+        crate::RobustScaler::new().into()
+    }
+
+    /// Fits the scaler to 2D data (see [`crate::RobustScaler::fit`]).
+    ///
+    /// # Panics
+    /// If the data has no rows or no columns.
+    pub fn fit<S: Data<Elem = f64>>(self, data: &ArrayBase<S, Ix2>) -> RobustScaler<Fitted> {
+        self.try_fit(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fits the scaler to 2D data, returning an error instead of panicking.
+    pub fn try_fit<S: Data<Elem = f64>>(
+        mut self,
+        data: &ArrayBase<S, Ix2>,
+    ) -> Result<RobustScaler<Fitted>, ScalerError> {
+        self.inner.try_fit(data)?;
+        Ok(RobustScaler {
+            inner: self.inner,
+            state: PhantomData,
+        })
+    }
+}
+
+/// Uses the settings (quantile range, names, clip, ...) of a dynamic scaler;
+/// its fitted parameters, if any, are replaced by `fit`.
+impl From<crate::RobustScaler> for RobustScaler<Unfitted> {
+    fn from(inner: crate::RobustScaler) -> Self {
+        Self {
+            inner,
+            state: PhantomData,
+        }
+    }
+}
+
+impl RobustScaler<Fitted> {
+    /// Loads a fitted scaler from a JSON file (see [`crate::RobustScaler::from_json`]).
+    #[cfg(all(feature = "std", feature = "json"))]
+    pub fn from_json<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        Ok(crate::RobustScaler::from_json(path)?.try_into()?)
+    }
+
+    /// Returns the dynamic scaler, e.g. to refit it.
+    pub fn into_inner(self) -> crate::RobustScaler {
+        self.inner
+    }
+}
+
+/// Checks that a dynamic scaler has parameters.
+///
+/// # Returns
+/// `ScalerError::NotFitted` if it has no features.
+impl TryFrom<crate::RobustScaler> for RobustScaler<Fitted> {
+    type Error = ScalerError;
+
+    fn try_from(inner: crate::RobustScaler) -> Result<Self, ScalerError> {
+        if inner.n_features() == 0 {
+            return Err(ScalerError::NotFitted);
+        }
+        Ok(Self {
+            inner,
+            state: PhantomData,
+        })
+    }
+}
+
+impl Deref for RobustScaler<Fitted> {
+    type Target = crate::RobustScaler;
+
+    fn deref(&self) -> &crate::RobustScaler {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_typestate() {
+        let data = arr2(&[[1.0, 10.0], [2.0, 20.0], [3.0, 30.0]]);
+        let settings = crate::RobustScaler::new().with_clip(-0.5, 0.5);
+        let fitted = RobustScaler::from(settings).fit(&data);
+        assert_eq!(fitted.center(), &[2.0, 20.0]);
+        assert_eq!(
+            fitted.transform(&data).column(0),
+            ndarray::aview1(&[-0.5, 0.0, 0.5])
+        );
+
+        let err = RobustScaler::new().try_fit(&ndarray::Array2::<f64>::zeros((0, 2)));
+        assert!(matches!(err, Err(ScalerError::EmptyInput { .. })));
+
+        let unfitted = RobustScaler::<Fitted>::try_from(crate::RobustScaler::new());
+        assert_eq!(unfitted.err(), Some(ScalerError::NotFitted));
+        let loaded = crate::RobustScaler::from_params(vec![1.0], vec![2.0]).unwrap();
+        assert!(RobustScaler::<Fitted>::try_from(loaded).is_ok());
+    }
+}
@@ -0,0 +1,135 @@
+use alloc::vec::Vec;
+use ndarray::{concatenate, s, Array2, ArrayView2, Axis};
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::{PipelineStep, Transformer};
+
+/// FeatureUnion: runs several transformers on the same input and stacks their
+/// outputs side by side.
+///
+/// E.g. robust-scaled features next to their one-hot or winsorized versions.
+/// Every branch must keep the number of rows. Branches are pipeline steps, so
+/// a union can be saved as a step of a `Pipeline`.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct FeatureUnion {
+    branches: Vec<PipelineStep>,
+    widths_: Vec<usize>, // Number of output columns of each branch
+}
+
+impl Default for FeatureUnion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeatureUnion {
+    /// Creates an empty union.
+    pub fn new() -> Self {
+        Self {
+            branches: Vec::new(),
+            widths_: Vec::new(),
+        }
+    }
+
+    /// Adds a branch; its output columns come after those of the previous branches.
+    ///
+    /// Any `Transformer` can be added with [`PipelineStep::custom`].
+    pub fn with<T: Into<PipelineStep>>(mut self, step: T) -> Self {
+        self.branches.push(step.into());
+        self
+    }
+
+    /// Fits every branch on `data`.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    pub fn fit(&mut self, data: &Array2<f64>) -> &Self {
+        self.fit_transform(data);
+        self
+    }
+
+    /// Transforms `data` with every branch and concatenates the outputs.
+    ///
+    /// # Panics
+    /// If the union is empty or a branch changes the number of rows.
+    pub fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        let outputs: Vec<Array2<f64>> = self
+            .branches
+            .iter()
+            .map(|b| b.as_transformer().transform(data))
+            .collect();
+        stack(&outputs)
+    }
+
+    /// Maps the output of the union back through its first branch, the only
+    /// one whose columns are needed to recover the input.
+    pub fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        assert!(!self.branches.is_empty(), "FeatureUnion has no branches");
+        assert_eq!(data.ncols(), self.widths_.iter().sum::<usize>());
+        let first = data.slice(s![.., ..self.widths_[0]]).to_owned();
+        self.branches[0].as_transformer().inverse_transform(&first)
+    }
+
+    /// Fits every branch and concatenates their outputs on the training data.
+    pub fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        let outputs: Vec<Array2<f64>> = self
+            .branches
+            .iter_mut()
+            .map(|b| b.as_transformer_mut().fit_transform(data))
+            .collect();
+        self.widths_ = outputs.iter().map(|o| o.ncols()).collect();
+        stack(&outputs)
+    }
+
+    /// Returns the number of output columns of each branch, as seen during `fit`.
+    pub fn widths(&self) -> &[usize] {
+        &self.widths_
+    }
+}
+
+impl Transformer for FeatureUnion {
+    fn fit(&mut self, data: &Array2<f64>) {
+        FeatureUnion::fit(self, data);
+    }
+
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        FeatureUnion::transform(self, data)
+    }
+
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        FeatureUnion::inverse_transform(self, data)
+    }
+
+    fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        FeatureUnion::fit_transform(self, data)
+    }
+}
+
+/// Concatenates the branch outputs along the columns.
+fn stack(outputs: &[Array2<f64>]) -> Array2<f64> {
+    assert!(!outputs.is_empty(), "FeatureUnion has no branches");
+    let views: Vec<ArrayView2<f64>> = outputs.iter().map(|o| o.view()).collect();
+    concatenate(Axis(1), &views).expect("Every branch must keep the number of rows")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RobustScaler, Winsorizer};
+    use ndarray::arr2;
+
+    #[test]
+    fn test_feature_union() {
+        let data = arr2(&[[1.0], [3.0], [5.0], [100.0]]);
+        let mut union = FeatureUnion::new()
+            .with(RobustScaler::new())
+            .with(Winsorizer::new(0.0, 0.5));
+        let stacked = union.fit_transform(&data);
+
+        assert_eq!(union.widths(), &[1, 1]);
+        assert_eq!(stacked.column(1), ndarray::aview1(&[1.0, 3.0, 4.0, 4.0]));
+        assert_eq!(union.transform(&data), stacked);
+        assert_eq!(union.inverse_transform(&stacked), data);
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+use crate::{check_fit_values, check_not_empty, quantile_sorted, sorted, ScalerError, Transformer};
+
+/// Winsorizer: caps each feature at a lower and upper quantile learned during `fit`.
+///
+/// Typically used before `RobustScaler` to cap features at their 1st/99th percentile.
+/// Capping is not reversible, so `inverse_transform` is the identity.
+#[derive(Serialize, Deserialize)]
+pub struct Winsorizer {
+    lower_quantile: f64,
+    upper_quantile: f64,
+    lower_: Vec<f64>, // Lower cap of each feature
+    upper_: Vec<f64>, // Upper cap of each feature
+}
+
+impl Default for Winsorizer {
+    fn default() -> Self {
+        Self::new(0.01, 0.99)
+    }
+}
+
+impl Winsorizer {
+    /// Creates a new unfitted Winsorizer capping at the given quantiles.
+    ///
+    /// # Arguments
+    /// * `lower_quantile` - Quantile of the lower cap (0.0 ≤ q ≤ 1.0).
+    /// * `upper_quantile` - Quantile of the upper cap, at least `lower_quantile`.
+    pub fn new(lower_quantile: f64, upper_quantile: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&lower_quantile)
+                && (0.0..=1.0).contains(&upper_quantile)
+                && lower_quantile <= upper_quantile,
+            "Invalid quantile range ({}, {})",
+            lower_quantile,
+            upper_quantile
+        );
+        Self {
+            lower_quantile,
+            upper_quantile,
+            lower_: vec![],
+            upper_: vec![],
+        }
+    }
+
+    /// Fits the caps of each feature.
+    ///
+    /// # Arguments
+    /// * `data` - A 2D array where rows are samples and columns are features.
+    ///
+    /// # Returns
+    /// &Self for chaining.
+    ///
+    /// # Panics
+    /// If the data has no rows or no columns, or holds a NaN or infinite value.
+    /// See [`Winsorizer::try_fit`] for a non-panicking version.
+    pub fn fit(&mut self, data: &Array2<f64>) -> &Self {
+        self.try_fit(data).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fits the caps of each feature, returning an error instead of panicking.
+    ///
+    /// # Returns
+    /// `Ok(&Self)` for chaining, or the errors of [`crate::RobustScaler::try_fit`];
+    /// the caps are left untouched on error.
+    pub fn try_fit(&mut self, data: &Array2<f64>) -> Result<&Self, ScalerError> {
+        check_not_empty(data.nrows(), data.ncols())?;
+        check_fit_values(None, data.columns())?;
+
+        let (lower, upper) = data
+            .columns()
+            .into_iter()
+            .map(|column| {
+                let sorted = sorted(&column);
+                (
+                    quantile_sorted(&sorted, self.lower_quantile),
+                    quantile_sorted(&sorted, self.upper_quantile),
+                )
+            })
+            .unzip();
+        self.lower_ = lower;
+        self.upper_ = upper;
+
+        Ok(self)
+    }
+
+    /// Clamps every value to the fitted caps of its feature.
+    pub fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        assert_eq!(data.ncols(), self.lower_.len());
+
+        let mut result = data.clone();
+        for (i, (&lower, &upper)) in self.lower_.iter().zip(&self.upper_).enumerate() {
+            result.column_mut(i).mapv_inplace(|x| x.clamp(lower, upper));
+        }
+        result
+    }
+
+    /// Returns the data unchanged: capped values cannot be recovered.
+    pub fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        assert_eq!(data.ncols(), self.lower_.len());
+        data.clone()
+    }
+
+    /// Fits the caps and transforms the data in one step.
+    pub fn fit_transform(&mut self, data: &Array2<f64>) -> Array2<f64> {
+        self.fit(data);
+        self.transform(data)
+    }
+
+    /// Returns the fitted lower cap of each feature.
+    pub fn lower(&self) -> &[f64] {
+        &self.lower_
+    }
+
+    /// Returns the fitted upper cap of each feature.
+    pub fn upper(&self) -> &[f64] {
+        &self.upper_
+    }
+}
+
+impl Transformer for Winsorizer {
+    fn fit(&mut self, data: &Array2<f64>) {
+        Winsorizer::fit(self, data);
+    }
+
+    fn transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        Winsorizer::transform(self, data)
+    }
+
+    fn inverse_transform(&self, data: &Array2<f64>) -> Array2<f64> {
+        Winsorizer::inverse_transform(self, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_winsorizer() {
+        let data = arr2(&[[0.0], [1.0], [2.0], [3.0], [100.0]]);
+        let mut winsorizer = Winsorizer::new(0.25, 0.75);
+        let capped = winsorizer.fit_transform(&data);
+
+        assert_eq!(winsorizer.lower(), &[1.0]);
+        assert_eq!(winsorizer.upper(), &[3.0]);
+        assert_eq!(capped, arr2(&[[1.0], [1.0], [2.0], [3.0], [3.0]]));
+        assert_eq!(winsorizer.inverse_transform(&capped), capped);
+
+        assert_eq!(
+            winsorizer.try_fit(&Array2::zeros((0, 1))).err(),
+            Some(ScalerError::EmptyInput {
+                n_samples: 0,
+                n_features: 1
+            })
+        );
+        assert_eq!(winsorizer.upper(), &[3.0]);
+    }
+}
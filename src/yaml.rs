@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use crate::format::{scaler_from_document, ScalerFile};
+use crate::{RobustScaler, ScalerError};
+
+impl RobustScaler {
+    /// Loads a scaler from a YAML file (enabled by the `yaml` feature).
+    ///
+    /// Uses the same schema as [`RobustScaler::from_json`], so a JSON export can
+    /// be converted to YAML with any tool.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the YAML file.
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)` if successful, `Err` otherwise.
+    pub fn from_yaml<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self::from_yaml_str(&text)?)
+    }
+
+    /// Loads a scaler from a YAML string, using the same schema as [`RobustScaler::from_json`].
+    ///
+    /// # Returns
+    /// `Ok(RobustScaler)`, or `ScalerError::InvalidFile` if the string is not a valid scaler.
+    pub fn from_yaml_str(text: &str) -> Result<Self, ScalerError> {
+        scaler_from_document(serde_norway::from_str(text))
+    }
+
+    /// Saves the scaler to a YAML file using the same schema as [`RobustScaler::to_json`].
+    ///
+    /// # Arguments
+    /// * `path` - Path of the YAML file to create.
+    pub fn to_yaml<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_yaml_string())
+    }
+
+    /// Serializes the scaler to a YAML string.
+    pub fn to_yaml_string(&self) -> String {
+        // YAML can represent every map, sequence, string and number
+        serde_norway::to_string(&ScalerFile::from_scaler(self))
+            .expect("A scaler file is representable in YAML")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::arr2;
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let mut scaler = RobustScaler::new()
+            .with_clip(-3.0, 3.0)
+            .with_feature_names(vec!["a", "b"])
+            .with_tag("model", "churn");
+        scaler.fit(&arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]]));
+
+        let path = std::env::temp_dir().join("robust_scaler_test_yaml_round_trip.yaml");
+        scaler.to_yaml(&path).unwrap();
+        let loaded = RobustScaler::from_yaml(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.center(), scaler.center());
+        assert_eq!(loaded.feature_names(), scaler.feature_names());
+        assert_eq!(loaded.metadata(), scaler.metadata());
+
+        // A hand-written file, with comments and block sequences
+        let text = "# exported from the feature store\n\
+                    center_: [1.0, 2.0]\n\
+                    scale_:\n  - 0.5\n  - 4.0\n\
+                    n_features_in_: 2\n";
+        let loaded = RobustScaler::from_yaml_str(text).unwrap();
+        assert_eq!(loaded.scale(), &[0.5, 4.0]);
+
+        match RobustScaler::from_yaml_str("center_: [1.0\nscale_: [1.0]") {
+            Err(err @ ScalerError::InvalidFile { .. }) => assert!(err.to_string().contains("line")),
+            _ => panic!("an unclosed sequence must be an InvalidFile error"),
+        }
+    }
+}